@@ -0,0 +1,40 @@
+//! Measures the time taken to extract a `LinkPreview` from a parsed document,
+//! along with the overhead saved by reusing compiled selectors.
+//!
+//! Run with `cargo bench --bench extraction`.
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use link_preview::{html_from_bytes, LinkPreview};
+use scraper::Selector;
+
+const FULL_FEATURED_HTML: &[u8] = include_bytes!("../html/full_featured.html");
+
+fn extraction(c: &mut Criterion) {
+    let html = html_from_bytes(FULL_FEATURED_HTML).unwrap();
+
+    c.bench_function("LinkPreview::from", |b| {
+        b.iter(|| LinkPreview::from(black_box(&html)))
+    });
+}
+
+fn selectors(c: &mut Criterion) {
+    let html = html_from_bytes(FULL_FEATURED_HTML).unwrap();
+    let selector = Selector::parse("meta[property=\"og:title\"]").unwrap();
+    let mut group = c.benchmark_group("select");
+
+    group.bench_function("parsing the selector per call", |b| {
+        b.iter(|| {
+            let selector = Selector::parse(black_box("meta[property=\"og:title\"]")).unwrap();
+
+            html.select(&selector).next().is_some()
+        })
+    });
+    group.bench_function("reusing a compiled selector", |b| {
+        b.iter(|| html.select(black_box(&selector)).next().is_some())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, extraction, selectors);
+criterion_main!(benches);
@@ -0,0 +1,221 @@
+//! Breadcrumbs describing the position of a page within its site hierarchy
+use scraper::Html;
+use url::Url;
+
+#[cfg(feature = "json")]
+use serde_json::Value;
+
+use crate::config::ExtractionConfig;
+use crate::LinkPreview;
+
+#[cfg(feature = "json")]
+use crate::providers::jsonld::{find_jsonld_entity, jsonld_name, jsonld_str};
+
+/// A single step in a breadcrumb trail
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Breadcrumb {
+    pub name: String,
+    /// Page the step links to, usually missing for the current page
+    pub url: Option<Url>,
+}
+
+/// Retrieves the breadcrumb trail declared by the first JSON-LD
+/// `BreadcrumbList` entity, ordered by each item's `position`.
+///
+/// Items without a name, neither their own nor their `item`'s, are skipped.
+#[cfg(feature = "json")]
+pub fn find_breadcrumbs(html: &Html) -> Vec<Breadcrumb> {
+    let Some(list) = find_jsonld_entity(html, "BreadcrumbList") else {
+        return Vec::new();
+    };
+    let Some(Value::Array(items)) = list.get("itemListElement") else {
+        return Vec::new();
+    };
+    let mut items: Vec<&Value> = items.iter().collect();
+
+    items.sort_by_key(|item| item.get("position").and_then(Value::as_u64));
+    items
+        .into_iter()
+        .filter_map(|item| {
+            let target = item.get("item");
+            let name = jsonld_str(item, "name").or_else(|| target.and_then(jsonld_name))?;
+            let url = target
+                .and_then(|target| match target {
+                    Value::String(url) => Some(url.to_string()),
+                    target => jsonld_str(target, "@id").or_else(|| jsonld_str(target, "url")),
+                })
+                .and_then(|url| Url::parse(&url).ok());
+
+            Some(Breadcrumb { name, url })
+        })
+        .collect()
+}
+
+/// Retrieves the breadcrumb trail declared through JSON-LD, always empty as
+/// JSON-LD isn't parsed without the `json` feature
+#[cfg(not(feature = "json"))]
+pub fn find_breadcrumbs(_html: &Html) -> Vec<Breadcrumb> {
+    Vec::new()
+}
+
+/// Same as `find_breadcrumbs`, additionally deriving the breadcrumb trail
+/// from the path of the canonical URL (or `ExtractionConfig::base_url`) when
+/// `ExtractionConfig::url_breadcrumbs` is enabled and the document declares
+/// none.
+///
+/// Each path segment becomes a breadcrumb named after the segment with
+/// hyphens and underscores replaced by spaces and every word capitalized,
+/// e.g. `indoor-plants` becomes `Indoor Plants`. This is a weak source, as
+/// paths don't necessarily reflect the site hierarchy.
+pub fn find_breadcrumbs_with_config(html: &Html, config: &ExtractionConfig) -> Vec<Breadcrumb> {
+    let breadcrumbs = find_breadcrumbs(html);
+
+    if !breadcrumbs.is_empty() || !config.url_breadcrumbs {
+        return breadcrumbs;
+    }
+
+    LinkPreview::find_first_canonical_url(html)
+        .or_else(|| config.base_url.clone())
+        .map(|url| url_breadcrumbs(&url))
+        .unwrap_or_default()
+}
+
+/// Builds a breadcrumb for every non-empty segment of the URL path, each one
+/// linking to the path up to its segment
+fn url_breadcrumbs(url: &Url) -> Vec<Breadcrumb> {
+    let Some(segments) = url.path_segments() else {
+        return Vec::new();
+    };
+    let segments: Vec<&str> = segments.filter(|segment| !segment.is_empty()).collect();
+
+    segments
+        .iter()
+        .enumerate()
+        .map(|(index, segment)| {
+            let mut target = url.clone();
+            let path = segments[..=index].join("/");
+
+            target.set_query(None);
+            target.set_fragment(None);
+
+            if index + 1 < segments.len() {
+                target.set_path(&format!("/{path}/"));
+            } else {
+                target.set_path(&format!("/{path}"));
+            }
+
+            Breadcrumb {
+                name: segment_name(segment),
+                url: Some(target),
+            }
+        })
+        .collect()
+}
+
+/// Turns a URL path segment into a title-cased name, e.g. `indoor-plants`
+/// into `Indoor Plants`
+fn segment_name(segment: &str) -> String {
+    segment
+        .split(['-', '_'])
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut characters = word.chars();
+
+            match characters.next() {
+                Some(first) => first.to_uppercase().chain(characters).collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html_from_bytes;
+    use crate::tests::{OG_COMPLIANT_HTML, URL_BREADCRUMBS_HTML};
+    use crate::ExtractionConfig;
+
+    #[cfg(feature = "json")]
+    use crate::tests::JSONLD_GRAPH_HTML;
+
+    use super::{find_breadcrumbs, find_breadcrumbs_with_config};
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn finds_breadcrumbs_by_position() {
+        let html = html_from_bytes(JSONLD_GRAPH_HTML).unwrap();
+        let breadcrumbs = find_breadcrumbs(&html);
+        let names: Vec<&str> = breadcrumbs
+            .iter()
+            .map(|breadcrumb| breadcrumb.name.as_str())
+            .collect();
+
+        assert_eq!(
+            names,
+            vec!["Home", "Guides", "Repotting a monstera without the mess"]
+        );
+        assert_eq!(
+            breadcrumbs[1].url.as_ref().unwrap().as_str(),
+            "https://leafandloam.example.com/guides/"
+        );
+        assert!(breadcrumbs[2].url.is_none());
+    }
+
+    #[test]
+    fn finds_no_breadcrumbs() {
+        let html = html_from_bytes(OG_COMPLIANT_HTML).unwrap();
+
+        assert!(find_breadcrumbs(&html).is_empty());
+    }
+
+    #[test]
+    fn derives_breadcrumbs_from_url_path_when_enabled() {
+        let html = html_from_bytes(URL_BREADCRUMBS_HTML).unwrap();
+        let config = ExtractionConfig {
+            url_breadcrumbs: true,
+            ..Default::default()
+        };
+
+        assert!(find_breadcrumbs(&html).is_empty());
+        assert!(find_breadcrumbs_with_config(&html, &ExtractionConfig::default()).is_empty());
+
+        let breadcrumbs = find_breadcrumbs_with_config(&html, &config);
+        let names: Vec<&str> = breadcrumbs
+            .iter()
+            .map(|breadcrumb| breadcrumb.name.as_str())
+            .collect();
+        let urls: Vec<&str> = breadcrumbs
+            .iter()
+            .map(|breadcrumb| breadcrumb.url.as_ref().unwrap().as_str())
+            .collect();
+
+        assert_eq!(
+            names,
+            vec!["Guides", "Indoor Plants", "Repotting A Monstera"]
+        );
+        assert_eq!(
+            urls,
+            vec![
+                "https://leafandloam.example.com/guides/",
+                "https://leafandloam.example.com/guides/indoor_plants/",
+                "https://leafandloam.example.com/guides/indoor_plants/repotting-a-monstera",
+            ]
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn prefers_jsonld_breadcrumbs_over_url_path() {
+        let html = html_from_bytes(JSONLD_GRAPH_HTML).unwrap();
+        let config = ExtractionConfig {
+            url_breadcrumbs: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            find_breadcrumbs_with_config(&html, &config),
+            find_breadcrumbs(&html)
+        );
+    }
+}
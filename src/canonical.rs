@@ -0,0 +1,123 @@
+//! Validation of the canonical URL declared by a document against the URL
+//! it was retrieved from, useful to detect syndicated or duplicated content.
+use scraper::Html;
+use url::Url;
+
+use crate::html::find_link;
+use crate::urls::resolve_url;
+use crate::LinkPreview;
+
+/// A canonical URL pointing somewhere other than the URL the document was
+/// retrieved from
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CanonicalMismatch {
+    /// Canonical URL declared by the document
+    pub canonical: Url,
+    /// URL the document was retrieved from
+    pub fetched: Url,
+    /// Whether the canonical URL points to another host
+    pub host_differs: bool,
+    /// Whether the canonical URL points to another path
+    pub path_differs: bool,
+}
+
+/// Compares the canonical URL declared by the document with the URL it was
+/// retrieved from (`fetched`), reporting a `CanonicalMismatch` when they
+/// differ in host or path.
+///
+/// The canonical URL is read from `<link rel="canonical">`, resolved against
+/// `fetched` when relative, falling back to
+/// `LinkPreview::find_first_canonical_url`. Hosts are compared
+/// case-insensitively and paths ignoring a trailing slash, while query
+/// strings (including tracking parameters) and fragments are ignored.
+///
+/// `None` is returned when the URLs match or when the document declares no
+/// canonical URL.
+pub fn canonical_mismatch(html: &Html, fetched: &Url) -> Option<CanonicalMismatch> {
+    let canonical = find_link(html, "canonical")
+        .and_then(|canonical| resolve_url(&canonical, Some(fetched)))
+        .or_else(|| LinkPreview::find_first_canonical_url(html))?;
+    let host_differs = !canonical
+        .host_str()
+        .unwrap_or_default()
+        .eq_ignore_ascii_case(fetched.host_str().unwrap_or_default());
+    let path_differs = trim_trailing_slash(canonical.path()) != trim_trailing_slash(fetched.path());
+
+    if !host_differs && !path_differs {
+        return None;
+    }
+
+    Some(CanonicalMismatch {
+        canonical,
+        fetched: fetched.clone(),
+        host_differs,
+        path_differs,
+    })
+}
+
+/// Removes a single trailing slash from the provided URL path
+fn trim_trailing_slash(path: &str) -> &str {
+    path.strip_suffix('/').unwrap_or(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use url::Url;
+
+    use crate::html_from_bytes;
+    use crate::tests::{CANONICAL_MATCH_HTML, CANONICAL_MISMATCH_HTML, OG_COMPLIANT_HTML};
+
+    use super::canonical_mismatch;
+
+    #[test]
+    fn finds_no_mismatch_for_matching_canonical() {
+        let html = html_from_bytes(CANONICAL_MATCH_HTML).unwrap();
+
+        for fetched in [
+            "https://travel.example.com/guides/lisbon-in-three-days",
+            "https://TRAVEL.example.com/guides/lisbon-in-three-days/?utm_source=newsletter",
+            "https://travel.example.com/guides/lisbon-in-three-days#day-2",
+        ] {
+            let fetched = Url::parse(fetched).unwrap();
+
+            assert!(canonical_mismatch(&html, &fetched).is_none(), "{fetched}");
+        }
+    }
+
+    #[test]
+    fn reports_mismatching_path() {
+        let html = html_from_bytes(CANONICAL_MATCH_HTML).unwrap();
+        let fetched = Url::parse("https://travel.example.com/amp/lisbon-in-three-days").unwrap();
+        let mismatch = canonical_mismatch(&html, &fetched).unwrap();
+
+        assert!(!mismatch.host_differs);
+        assert!(mismatch.path_differs);
+        assert_eq!(mismatch.fetched, fetched);
+    }
+
+    #[test]
+    fn reports_syndicated_canonical() {
+        let html = html_from_bytes(CANONICAL_MISMATCH_HTML).unwrap();
+        let fetched = Url::parse("https://news.example.net/world/lisbon-trams").unwrap();
+        let mismatch = canonical_mismatch(&html, &fetched).unwrap();
+
+        assert_eq!(
+            mismatch.canonical.as_str(),
+            "https://www.lisbondaily.example.pt/city/lisbon-trams"
+        );
+        assert!(mismatch.host_differs);
+        assert!(mismatch.path_differs);
+    }
+
+    #[test]
+    fn falls_back_to_og_url() {
+        let html = html_from_bytes(OG_COMPLIANT_HTML).unwrap();
+        let fetched = Url::parse("https://abetterweb.com").unwrap();
+
+        assert!(canonical_mismatch(&html, &fetched).is_none());
+
+        let fetched = Url::parse("https://mirror.example.com").unwrap();
+
+        assert!(canonical_mismatch(&html, &fetched).unwrap().host_differs);
+    }
+}
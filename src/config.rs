@@ -0,0 +1,107 @@
+use url::Url;
+
+use crate::providers::meta::MetaProvider;
+use crate::providers::source::ExtractionSource;
+
+/// Filename patterns which usually identify non-hero images
+const DEFAULT_EXCLUDE_IMAGE_PATTERNS: [&str; 4] = ["logo", "sprite", "icon", "avatar"];
+
+/// Sources consulted for the title and description, in order of preference
+const DEFAULT_PROVIDER_ORDER: [ExtractionSource; 3] = [
+    ExtractionSource::OpenGraph,
+    ExtractionSource::Twitter,
+    ExtractionSource::Schema,
+];
+
+/// Default minimum declared `width`/`height` for content images
+const DEFAULT_MIN_IMAGE_SIZE: u32 = 100;
+
+/// Options for the optional heuristics used while extracting a `LinkPreview`.
+///
+/// The `Default` implementation keeps every optional heuristic disabled, so
+/// extraction relies on the document's metadata and, as it always did, on its
+/// first paragraph for the description.
+#[derive(Clone, Debug)]
+pub struct ExtractionConfig {
+    /// Falls back to the first image inside the document's `<article>` or
+    /// `<main>` element when no image is declared through meta tags.
+    pub content_image_fallback: bool,
+    /// Images found by the content image fallback whose URL path contains any
+    /// of these patterns (compared case-insensitively) are skipped.
+    ///
+    /// Defaults to `logo`, `sprite`, `icon` and `avatar`.
+    pub exclude_image_patterns: Vec<String>,
+    /// URL the document was retrieved from. When present, relative URLs are
+    /// resolved against it and its domain is used when the document doesn't
+    /// declare one.
+    pub base_url: Option<Url>,
+    /// Falls back to the text of the first non-empty `<figcaption>` when no
+    /// description is found, not even a paragraph.
+    pub figcaption_fallback: bool,
+    /// Falls back to the first paragraph of the document's main content when
+    /// no description is declared through meta tags. Enabled by default.
+    pub paragraph_fallback: bool,
+    /// Uses the longest description found across every source instead of
+    /// the first one in order of preference.
+    pub prefer_longest_description: bool,
+    /// Minimum declared `width`/`height` for an image to be picked by the
+    /// content image fallback. Defaults to `100`.
+    pub min_image_size: u32,
+    /// Also considers images inside `<noscript>` elements for the content
+    /// image fallback, which lazy loading pages use to hold the actual
+    /// images. These are considered after every other content image.
+    pub noscript: bool,
+    /// Derives breadcrumbs from the path segments of the canonical URL when
+    /// the document doesn't declare them through JSON-LD.
+    pub url_breadcrumbs: bool,
+    /// Meta tag sources consulted for the title and description, in order of
+    /// preference. Sources not declared through meta tags, such as
+    /// `ExtractionSource::Html`, are ignored.
+    ///
+    /// Images are only taken from the meta tags of the listed sources as
+    /// well, keeping their own priority.
+    ///
+    /// Defaults to OpenGraph, Twitter Cards and Schema.org.
+    pub provider_order: Vec<ExtractionSource>,
+}
+
+impl Default for ExtractionConfig {
+    fn default() -> Self {
+        ExtractionConfig {
+            content_image_fallback: false,
+            exclude_image_patterns: DEFAULT_EXCLUDE_IMAGE_PATTERNS
+                .iter()
+                .map(|pattern| pattern.to_string())
+                .collect(),
+            base_url: None,
+            figcaption_fallback: false,
+            paragraph_fallback: true,
+            prefer_longest_description: false,
+            min_image_size: DEFAULT_MIN_IMAGE_SIZE,
+            noscript: false,
+            url_breadcrumbs: false,
+            provider_order: DEFAULT_PROVIDER_ORDER.to_vec(),
+        }
+    }
+}
+
+impl ExtractionConfig {
+    /// Checks whether the provided URL path matches any of the
+    /// `exclude_image_patterns`
+    pub(crate) fn is_excluded_image_path(&self, path: &str) -> bool {
+        let path = path.to_lowercase();
+
+        self.exclude_image_patterns
+            .iter()
+            .any(|pattern| path.contains(&pattern.to_lowercase()))
+    }
+
+    /// Retrieves the `MetaProvider` for each of the `provider_order` sources
+    /// declared through meta tags
+    pub(crate) fn meta_providers(&self) -> Vec<&'static dyn MetaProvider> {
+        self.provider_order
+            .iter()
+            .filter_map(ExtractionSource::meta_provider)
+            .collect()
+    }
+}
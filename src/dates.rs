@@ -0,0 +1,236 @@
+//! Dates declared by the document, such as the last time it was modified.
+//!
+//! Dates are retrieved as declared by the document. Enable the `chrono`
+//! feature to parse them into `DateTime` instances.
+use std::sync::LazyLock;
+
+use scraper::{Html, Selector};
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime};
+
+use crate::html::{find_meta_by_http_equiv, find_meta_tag};
+use crate::providers::jsonld::{find_jsonld_tag, JsonLdField};
+
+/// `<time>` elements declaring when the document was published
+static PUBLISHED_TIME_ELEMENT: LazyLock<Selector> =
+    LazyLock::new(|| Selector::parse("time[itemprop=\"datePublished\"][datetime]").unwrap());
+
+/// `<time>` elements declaring when the document was modified
+static MODIFIED_TIME_ELEMENT: LazyLock<Selector> =
+    LazyLock::new(|| Selector::parse("time[itemprop=\"dateModified\"][datetime]").unwrap());
+
+/// Any `<time>` element declaring a date
+static TIME_ELEMENT: LazyLock<Selector> =
+    LazyLock::new(|| Selector::parse("time[datetime]").unwrap());
+
+/// Retrieves the time the document was published, as declared, in the
+/// following order:
+///
+/// - OpenGraph's `article:published_time` meta tag
+/// - The `datePublished` of the JSON-LD entity describing the page
+/// - The `<time itemprop="datePublished">` element
+/// - The first `<time>` element with a `datetime` attribute
+pub fn find_published_time(html: &Html) -> Option<String> {
+    find_meta_tag(html, "article:published_time")
+        .or_else(|| find_jsonld_tag(html, JsonLdField::DatePublished))
+        .or_else(|| find_time_element(html, &PUBLISHED_TIME_ELEMENT))
+        .or_else(|| find_time_element(html, &TIME_ELEMENT))
+}
+
+/// Same as `find_published_time` but parses the date with `parse_date`
+#[cfg(feature = "chrono")]
+pub fn find_published_time_parsed(html: &Html) -> Option<DateTime<FixedOffset>> {
+    find_published_time(html).and_then(|value| parse_date(&value))
+}
+
+/// Retrieves the last time the document was modified, as declared, in the
+/// following order:
+///
+/// - OpenGraph's `article:modified_time` meta tag
+/// - The `dateModified` of the JSON-LD entity describing the page
+/// - The `<meta http-equiv="last-modified">` element
+/// - The `<time itemprop="dateModified">` element
+pub fn find_modified_time(html: &Html) -> Option<String> {
+    find_meta_tag(html, "article:modified_time")
+        .or_else(|| find_jsonld_tag(html, JsonLdField::DateModified))
+        .or_else(|| find_meta_by_http_equiv(html, "last-modified"))
+        .or_else(|| find_time_element(html, &MODIFIED_TIME_ELEMENT))
+}
+
+/// Same as `find_modified_time` but parses the date with `parse_date`
+#[cfg(feature = "chrono")]
+pub fn find_modified_time_parsed(html: &Html) -> Option<DateTime<FixedOffset>> {
+    find_modified_time(html).and_then(|value| parse_date(&value))
+}
+
+/// Retrieves the time the article described by the document expires, as
+/// declared by OpenGraph's `article:expiration_time` meta tag
+pub fn find_article_expiration(html: &Html) -> Option<String> {
+    find_meta_tag(html, "article:expiration_time")
+}
+
+/// Same as `find_article_expiration` but parses the date with `parse_date`
+#[cfg(feature = "chrono")]
+pub fn find_article_expiration_parsed(html: &Html) -> Option<DateTime<FixedOffset>> {
+    find_article_expiration(html).and_then(|value| parse_date(&value))
+}
+
+/// Parses a date in either of the following forms:
+///
+/// - ISO 8601 / RFC 3339, e.g. `2024-05-15T10:02:11+00:00`
+/// - HTTP-date / RFC 2822, e.g. `Wed, 15 May 2024 10:02:11 GMT`
+///
+/// ISO dates without an offset, or without a time, are assumed to be UTC.
+#[cfg(feature = "chrono")]
+pub fn parse_date(value: &str) -> Option<DateTime<FixedOffset>> {
+    let value = value.trim();
+
+    if let Ok(date) = DateTime::parse_from_rfc3339(value) {
+        return Some(date);
+    }
+
+    if let Ok(date) = DateTime::parse_from_rfc2822(value) {
+        return Some(date);
+    }
+
+    let naive = NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S")
+        .ok()
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+        })?;
+
+    Some(naive.and_utc().fixed_offset())
+}
+
+/// Retrieves the non-empty `datetime` attribute of the first `<time>` element
+/// matching the provided selector
+fn find_time_element(html: &Html, selector: &Selector) -> Option<String> {
+    html.select(selector)
+        .filter_map(|element| element.value().attr("datetime"))
+        .map(|value| value.trim())
+        .find(|value| !value.is_empty())
+        .map(|value| value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html_from_bytes;
+    use crate::tests::{
+        ARTICLE_EXPIRATION_HTML, JSONLD_ARTICLE_HTML, LAST_MODIFIED_HTML, PUBLISHED_TIMES_HTML,
+        TIME_ELEMENT_HTML, WORDPRESS_POST_HTML,
+    };
+
+    use super::{find_article_expiration, find_modified_time, find_published_time};
+
+    #[test]
+    fn finds_modified_time_from_article_tag() {
+        let html = html_from_bytes(WORDPRESS_POST_HTML).unwrap();
+
+        assert_eq!(
+            find_modified_time(&html).unwrap(),
+            "2024-05-15T10:02:11+00:00"
+        );
+    }
+
+    #[test]
+    fn falls_back_to_http_equiv_last_modified() {
+        let html = html_from_bytes(LAST_MODIFIED_HTML).unwrap();
+
+        assert_eq!(
+            find_modified_time(&html).unwrap(),
+            "Wed, 15 May 2024 10:02:11 GMT"
+        );
+    }
+
+    #[test]
+    fn finds_published_and_modified_time_from_article_tags() {
+        let html = html_from_bytes(PUBLISHED_TIMES_HTML).unwrap();
+
+        assert_eq!(
+            find_published_time(&html).unwrap(),
+            "2024-11-02T06:30:00+01:00"
+        );
+        assert_eq!(
+            find_modified_time(&html).unwrap(),
+            "2024-11-03T18:45:12+01:00"
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn falls_back_to_jsonld_dates() {
+        let html = html_from_bytes(JSONLD_ARTICLE_HTML).unwrap();
+
+        assert_eq!(
+            find_published_time(&html).unwrap(),
+            "2024-05-14T07:30:00+01:00"
+        );
+        assert!(find_modified_time(&html).is_none());
+    }
+
+    #[test]
+    fn falls_back_to_time_elements() {
+        let html = html_from_bytes(TIME_ELEMENT_HTML).unwrap();
+
+        assert_eq!(find_published_time(&html).unwrap(), "2024-03-18T09:00:00Z");
+        assert_eq!(find_modified_time(&html).unwrap(), "2024-03-20T14:10:00Z");
+        assert!(find_published_time(&html_from_bytes(LAST_MODIFIED_HTML).unwrap()).is_none());
+    }
+
+    #[test]
+    fn finds_article_expiration() {
+        let html = html_from_bytes(ARTICLE_EXPIRATION_HTML).unwrap();
+
+        assert_eq!(
+            find_article_expiration(&html).unwrap(),
+            "2024-12-31T23:59:59+01:00"
+        );
+        assert!(find_article_expiration(&html_from_bytes(WORDPRESS_POST_HTML).unwrap()).is_none());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn parses_article_expiration() {
+        use super::{find_article_expiration_parsed, parse_date};
+
+        let html = html_from_bytes(ARTICLE_EXPIRATION_HTML).unwrap();
+
+        assert_eq!(
+            find_article_expiration_parsed(&html).unwrap(),
+            parse_date("2024-12-31T22:59:59Z").unwrap()
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn parses_http_and_iso_dates() {
+        use super::{find_modified_time_parsed, parse_date};
+
+        let html = html_from_bytes(LAST_MODIFIED_HTML).unwrap();
+        let expected = parse_date("2024-05-15T10:02:11Z").unwrap();
+
+        assert_eq!(find_modified_time_parsed(&html).unwrap(), expected);
+        assert_eq!(parse_date("2024-05-15T10:02:11").unwrap(), expected);
+        assert_eq!(
+            parse_date("2024-05-15").unwrap(),
+            parse_date("2024-05-15T00:00:00+00:00").unwrap()
+        );
+        assert!(parse_date("last tuesday").is_none());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn parses_published_time() {
+        use super::{find_published_time_parsed, parse_date};
+
+        let html = html_from_bytes(PUBLISHED_TIMES_HTML).unwrap();
+
+        assert_eq!(
+            find_published_time_parsed(&html).unwrap(),
+            parse_date("2024-11-02T05:30:00Z").unwrap()
+        );
+    }
+}
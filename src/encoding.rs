@@ -0,0 +1,160 @@
+//! Character encoding detection for documents which aren't encoded in UTF-8,
+//! following a simplified version of the HTML encoding sniffing algorithm.
+use encoding_rs::{Encoding, UTF_16BE, UTF_16LE, UTF_8, WINDOWS_1252};
+
+/// Number of leading bytes scanned for a `<meta>` declaring the encoding
+const PRESCAN_LENGTH: usize = 1024;
+
+/// Detects the encoding of the provided document in the following order:
+///
+/// - A byte order mark (BOM)
+/// - The `<meta charset>` element
+/// - The `charset` parameter of a `<meta http-equiv="Content-Type">` element
+///
+/// Only the first 1024 bytes are scanned for `<meta>` elements. `None` is
+/// returned when the document doesn't declare any known encoding.
+pub(crate) fn sniff_encoding(bytes: &[u8]) -> Option<&'static Encoding> {
+    if let Some((encoding, _)) = Encoding::for_bom(bytes) {
+        return Some(encoding);
+    }
+
+    let prefix = &bytes[..bytes.len().min(PRESCAN_LENGTH)];
+    let encoding = meta_charsets(prefix).find_map(Encoding::for_label)?;
+
+    // As per the HTML specification, a UTF-16 declaration in a document read
+    // as ASCII is wrong, and `x-user-defined` maps to Windows-1252
+    if encoding == UTF_16LE || encoding == UTF_16BE {
+        return Some(UTF_8);
+    }
+
+    if encoding.name() == "x-user-defined" {
+        return Some(WINDOWS_1252);
+    }
+
+    Some(encoding)
+}
+
+/// Decodes the provided document with its declared encoding, replacing
+/// malformed sequences. `None` when the document is encoded in UTF-8 or
+/// doesn't declare its encoding, so it's decoded as UTF-8 instead.
+///
+/// The encoding labeled by `charset`, as declared by the `Content-Type` of
+/// the response the document was read from, is preferred over the one
+/// sniffed from the document. A byte order mark still takes precedence, and
+/// unknown labels are ignored.
+pub(crate) fn decode_legacy(bytes: &[u8], charset: Option<&str>) -> Option<String> {
+    let declared = || {
+        Encoding::for_bom(bytes)
+            .map(|(encoding, _)| encoding)
+            .or_else(|| charset.and_then(|charset| Encoding::for_label(charset.trim().as_bytes())))
+            .or_else(|| sniff_encoding(bytes))
+    };
+    let encoding = declared().filter(|encoding| *encoding != UTF_8)?;
+    let (text, _) = encoding.decode_with_bom_removal(bytes);
+
+    Some(text.into_owned())
+}
+
+/// Iterates over the `charset` values declared by every `<meta>` tag in the
+/// provided bytes
+fn meta_charsets(bytes: &[u8]) -> impl Iterator<Item = &[u8]> {
+    let mut position = 0;
+
+    std::iter::from_fn(move || loop {
+        let start = position + find(&bytes[position..], b"<meta")?;
+        let end = start + find(&bytes[start..], b">").unwrap_or(bytes.len() - start);
+        let tag = &bytes[start..end];
+
+        position = end;
+
+        if let Some(charset) = charset_in_tag(tag) {
+            return Some(charset);
+        }
+    })
+}
+
+/// Retrieves the value following the first `charset=` in the tag, unquoted
+fn charset_in_tag(tag: &[u8]) -> Option<&[u8]> {
+    let start = find(tag, b"charset")? + b"charset".len();
+    let rest = trim_start(&tag[start..]);
+    let rest = trim_start(rest.strip_prefix(b"=")?);
+    let rest = rest
+        .strip_prefix(b"\"")
+        .or_else(|| rest.strip_prefix(b"'"))
+        .unwrap_or(rest);
+    let end = rest
+        .iter()
+        .position(|byte| {
+            byte.is_ascii_whitespace() || matches!(byte, b'"' | b'\'' | b';' | b'/' | b'>')
+        })
+        .unwrap_or(rest.len());
+
+    Some(&rest[..end]).filter(|label| !label.is_empty())
+}
+
+/// Finds the position of `needle` in `haystack`, compared case-insensitively
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window.eq_ignore_ascii_case(needle))
+}
+
+fn trim_start(bytes: &[u8]) -> &[u8] {
+    let start = bytes
+        .iter()
+        .position(|byte| !byte.is_ascii_whitespace())
+        .unwrap_or(bytes.len());
+
+    &bytes[start..]
+}
+
+#[cfg(test)]
+mod tests {
+    use encoding_rs::{SHIFT_JIS, UTF_8, WINDOWS_1252};
+
+    use crate::tests::{OG_COMPLIANT_HTML, WINDOWS_1252_HTML};
+
+    use super::{decode_legacy, sniff_encoding};
+
+    #[test]
+    fn sniffs_encoding_from_meta_tags() {
+        assert_eq!(sniff_encoding(WINDOWS_1252_HTML), Some(WINDOWS_1252));
+        assert_eq!(
+            sniff_encoding(b"<html><head><META Charset = 'Shift_JIS'></head></html>"),
+            Some(SHIFT_JIS)
+        );
+        assert_eq!(
+            sniff_encoding(b"<meta charset=\"utf-16le\"><title>Hi</title>"),
+            Some(UTF_8)
+        );
+        assert_eq!(
+            sniff_encoding(b"<meta name=\"viewport\"><meta charset=\"unknown\">"),
+            None
+        );
+    }
+
+    #[test]
+    fn sniffs_encoding_from_bom() {
+        assert_eq!(
+            sniff_encoding(b"\xEF\xBB\xBF<meta charset=\"windows-1252\">"),
+            Some(UTF_8)
+        );
+    }
+
+    #[test]
+    fn decodes_legacy_encodings_only() {
+        let text = decode_legacy(WINDOWS_1252_HTML, None).unwrap();
+
+        assert!(text.contains("<title>Café Müller – Crêpes & Gâteaux</title>"));
+        assert!(decode_legacy(OG_COMPLIANT_HTML, None).is_none());
+    }
+
+    #[test]
+    fn prefers_charset_of_the_response() {
+        let text = decode_legacy(b"<p>Caf\xe9</p>", Some("windows-1252")).unwrap();
+
+        assert_eq!(text, "<p>Café</p>");
+        assert!(decode_legacy(WINDOWS_1252_HTML, Some("utf-8")).is_none());
+        assert!(decode_legacy(WINDOWS_1252_HTML, Some("unknown")).is_some());
+    }
+}
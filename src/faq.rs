@@ -0,0 +1,80 @@
+//! Frequently asked questions declared by the page
+use scraper::Html;
+use serde_json::Value;
+
+use crate::html::{decode_html_entities, normalize_whitespace, remove_html_tags};
+use crate::providers::jsonld::{find_jsonld_entities, has_type, jsonld_str};
+
+/// Retrieves the question and answer pairs declared by JSON-LD `FAQPage`
+/// entities, in document order. Questions without an `acceptedAnswer` are
+/// skipped.
+///
+/// Answers may hold HTML, so their tags are removed, their entities are
+/// decoded and their whitespace is normalized.
+pub fn find_faq(html: &Html) -> Vec<(String, String)> {
+    find_jsonld_entities(html)
+        .iter()
+        .filter(|entity| has_type(entity, "FAQPage"))
+        .flat_map(|page| match page.get("mainEntity") {
+            Some(Value::Array(questions)) => questions.clone(),
+            Some(question) => vec![question.clone()],
+            None => Vec::new(),
+        })
+        .filter(|question| has_type(question, "Question"))
+        .filter_map(|question| {
+            let answer = match question.get("acceptedAnswer")? {
+                Value::Array(answers) => answers.first()?,
+                answer => answer,
+            };
+            let question = clean_text(&jsonld_str(&question, "name")?);
+            let answer = clean_text(&jsonld_str(answer, "text")?);
+
+            (!question.is_empty() && !answer.is_empty()).then_some((question, answer))
+        })
+        .collect()
+}
+
+/// Removes the HTML tags and entities from the provided text and normalizes
+/// its whitespace
+fn clean_text(text: &str) -> String {
+    normalize_whitespace(&decode_html_entities(&remove_html_tags(text)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html_from_bytes;
+    use crate::tests::{FAQ_PAGE_HTML, JSONLD_GRAPH_HTML};
+
+    use super::find_faq;
+
+    #[test]
+    fn finds_faq_in_order() {
+        let html = html_from_bytes(FAQ_PAGE_HTML).unwrap();
+
+        assert_eq!(
+            find_faq(&html),
+            vec![
+                (
+                    "How long does shipping take?".to_string(),
+                    "Orders ship within 1-2 business days and arrive in 3-5 days within the EU."
+                        .to_string()
+                ),
+                (
+                    "Can I return an item?".to_string(),
+                    "Yes, unworn items can be returned within 30 days & refunds are issued to the original payment method.".to_string()
+                ),
+                (
+                    "Do you offer gift wrapping?".to_string(),
+                    "Gift wrapping is available at checkout for €4.".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn finds_no_faq_without_faq_page() {
+        let html = html_from_bytes(JSONLD_GRAPH_HTML).unwrap();
+
+        assert!(find_faq(&html).is_empty());
+    }
+}
@@ -0,0 +1,211 @@
+//! Favicon discovery
+//!
+//! Documents declare their icons through `<link>` elements, often many of
+//! them in different sizes and formats described by their `sizes` and `type`
+//! attributes.
+use std::sync::LazyLock;
+
+use scraper::{Html, Selector};
+use url::Url;
+
+use crate::html::document_base_url;
+use crate::urls::resolve_url;
+
+/// `rel` values of the `<link>` elements declaring the site's icon, by
+/// priority
+const FAVICON_RELS: [&str; 3] = ["icon", "shortcut icon", "apple-touch-icon"];
+
+/// An icon declared by a document
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Favicon {
+    pub url: Url,
+    /// Sizes declared for the icon, e.g. `32x32`, `16x16 32x32` or `any`
+    pub sizes: Option<String>,
+    /// Media type declared for the icon, e.g. `image/png`
+    pub mime: Option<String>,
+}
+
+impl Favicon {
+    /// Retrieves the largest dimension of the largest declared size, e.g.
+    /// `180` for `180x180`. Scalable icons (`any`) are the largest of all.
+    pub fn size(&self) -> Option<u32> {
+        largest_size(self.sizes.as_deref()?)
+    }
+}
+
+/// An icon `<link>` element as declared
+#[derive(Clone, Copy)]
+struct IconLink<'a> {
+    href: &'a str,
+    sizes: Option<&'a str>,
+    mime: Option<&'a str>,
+}
+
+/// Retrieves every icon declared by the provided `Html` instance through
+/// `<link rel="icon">`, `<link rel="shortcut icon">` and
+/// `<link rel="apple-touch-icon">` elements, in that order and then in
+/// document order. `rel` values are compared case-insensitively.
+///
+/// Icon URLs are resolved against the document's `<base href>` or `base`
+/// when provided, relative URLs which can't be resolved are skipped.
+pub fn find_all_favicons(html: &Html, base: Option<&Url>) -> Vec<Favicon> {
+    let base = document_base_url(html, base);
+
+    icon_links(html)
+        .into_iter()
+        .filter_map(|link| {
+            Some(Favicon {
+                url: resolve_url(link.href, base.as_ref())?,
+                sizes: link.sizes.map(|sizes| sizes.to_string()),
+                mime: link.mime.map(|mime| mime.to_string()),
+            })
+        })
+        .collect()
+}
+
+/// Retrieves the `href`, as declared, of the icon with the largest declared
+/// size. Icons without a valid size rank below any sized one, and ties are
+/// settled by the order of `find_all_favicons`.
+pub fn find_largest_favicon_href(html: &Html) -> Option<String> {
+    icon_links(html)
+        .into_iter()
+        .reduce(|largest, link| {
+            let size = |link: &IconLink| link.sizes.and_then(largest_size);
+
+            if size(&link) > size(&largest) {
+                link
+            } else {
+                largest
+            }
+        })
+        .map(|link| link.href.to_string())
+}
+
+/// Retrieves the icon `<link>` elements with a non-empty `href`, in order of
+/// `FAVICON_RELS` and then in document order
+fn icon_links(html: &Html) -> Vec<IconLink<'_>> {
+    static SELECTOR: LazyLock<Selector> =
+        LazyLock::new(|| Selector::parse("link[rel][href]").unwrap());
+    let links: Vec<(String, IconLink)> = html
+        .select(&SELECTOR)
+        .filter_map(|element| {
+            let element = element.value();
+            let rel = element
+                .attr("rel")?
+                .split_ascii_whitespace()
+                .collect::<Vec<&str>>()
+                .join(" ")
+                .to_lowercase();
+            let href = element.attr("href")?.trim();
+            let attr = |name: &str| {
+                element
+                    .attr(name)
+                    .map(|value| value.trim())
+                    .filter(|value| !value.is_empty())
+            };
+
+            (!href.is_empty()).then(|| {
+                (
+                    rel,
+                    IconLink {
+                        href,
+                        sizes: attr("sizes"),
+                        mime: attr("type"),
+                    },
+                )
+            })
+        })
+        .collect();
+    let mut icons: Vec<IconLink> = Vec::new();
+
+    for favicon_rel in FAVICON_RELS {
+        icons.extend(
+            links
+                .iter()
+                .filter(|(rel, _)| rel == favicon_rel)
+                .map(|(_, link)| *link),
+        );
+    }
+
+    icons
+}
+
+/// Retrieves the largest dimension across the sizes of a `sizes` attribute,
+/// e.g. `32` for `16x16 32x32`, where `any` is the largest of all
+fn largest_size(sizes: &str) -> Option<u32> {
+    sizes
+        .split_ascii_whitespace()
+        .filter_map(|size| {
+            if size.eq_ignore_ascii_case("any") {
+                return Some(u32::MAX);
+            }
+
+            let (width, height) = size.split_once(['x', 'X'])?;
+
+            Some(width.parse::<u32>().ok()?.max(height.parse::<u32>().ok()?))
+        })
+        .max()
+}
+
+#[cfg(test)]
+mod tests {
+    use url::Url;
+
+    use crate::html_from_bytes;
+    use crate::tests::{RELATIVE_FAVICON_HTML, SIZED_FAVICONS_HTML};
+
+    use super::{find_all_favicons, find_largest_favicon_href, largest_size};
+
+    #[test]
+    fn finds_favicons_with_size_and_type_hints() {
+        let html = html_from_bytes(SIZED_FAVICONS_HTML).unwrap();
+        let base = Url::parse("https://tides.example.com/harbours/").unwrap();
+        let favicons = find_all_favicons(&html, Some(&base));
+        let urls: Vec<&str> = favicons.iter().map(|favicon| favicon.url.path()).collect();
+
+        assert_eq!(
+            urls,
+            vec![
+                "/icons/favicon-16.png",
+                "/icons/favicon-48.png",
+                "/icons/android-chrome-192.png",
+                "/favicon.ico",
+                "/icons/apple-touch-icon.png",
+            ]
+        );
+        assert_eq!(favicons[1].sizes.as_deref(), Some("32x32 48x48"));
+        assert_eq!(favicons[1].mime.as_deref(), Some("image/png"));
+        assert_eq!(favicons[1].size(), Some(48));
+        assert_eq!(favicons[3].sizes, None);
+        assert_eq!(favicons[3].size(), None);
+    }
+
+    #[test]
+    fn picks_largest_favicon() {
+        let html = html_from_bytes(SIZED_FAVICONS_HTML).unwrap();
+
+        assert_eq!(
+            find_largest_favicon_href(&html).unwrap(),
+            "/icons/android-chrome-192.png"
+        );
+        assert_eq!(
+            find_largest_favicon_href(&html_from_bytes(RELATIVE_FAVICON_HTML).unwrap()).unwrap(),
+            "/favicon.ico"
+        );
+    }
+
+    #[test]
+    fn skips_relative_favicons_without_base() {
+        let html = html_from_bytes(SIZED_FAVICONS_HTML).unwrap();
+
+        assert!(find_all_favicons(&html, None).is_empty());
+    }
+
+    #[test]
+    fn parses_largest_declared_size() {
+        assert_eq!(largest_size("16x16 32X32"), Some(32));
+        assert_eq!(largest_size("120x60"), Some(120));
+        assert_eq!(largest_size("any"), Some(u32::MAX));
+        assert_eq!(largest_size("large"), None);
+    }
+}
@@ -0,0 +1,129 @@
+//! Feed discovery
+//!
+//! Documents advertise their RSS, Atom and JSON feeds through
+//! `<link rel="alternate">` elements declaring the feed's media type.
+use std::sync::LazyLock;
+
+use scraper::{Html, Selector};
+use url::Url;
+
+use crate::html::document_base_url;
+use crate::urls::resolve_url;
+
+/// Format of a feed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeedKind {
+    /// `application/rss+xml`
+    Rss,
+    /// `application/atom+xml`
+    Atom,
+    /// `application/feed+json`
+    Json,
+}
+
+impl FeedKind {
+    /// Retrieves the `FeedKind` for the provided media type, compared
+    /// case-insensitively and ignoring any parameter
+    fn from_media_type(media_type: &str) -> Option<Self> {
+        let media_type = media_type.split(';').next()?.trim().to_lowercase();
+
+        match media_type.as_str() {
+            "application/rss+xml" => Some(FeedKind::Rss),
+            "application/atom+xml" => Some(FeedKind::Atom),
+            "application/feed+json" => Some(FeedKind::Json),
+            _ => None,
+        }
+    }
+}
+
+/// A feed advertised by a document
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Feed {
+    pub url: Url,
+    /// Title declared for the feed, e.g. "Comments Feed"
+    pub title: Option<String>,
+    pub kind: FeedKind,
+}
+
+/// Retrieves every feed advertised by the provided `Html` instance in
+/// document order.
+///
+/// Feed URLs are resolved against the document's `<base href>` or `base`
+/// when provided, relative URLs which can't be resolved are skipped.
+pub fn find_feeds(html: &Html, base: Option<&Url>) -> Vec<Feed> {
+    let base = document_base_url(html, base);
+    static SELECTOR: LazyLock<Selector> =
+        LazyLock::new(|| Selector::parse("link[rel~=\"alternate\"][type][href]").unwrap());
+
+    html.select(&SELECTOR)
+        .filter_map(|element| {
+            let element = element.value();
+            let kind = FeedKind::from_media_type(element.attr("type")?)?;
+            let url = resolve_url(element.attr("href")?.trim(), base.as_ref())?;
+            let title = element
+                .attr("title")
+                .map(|title| title.trim().to_string())
+                .filter(|title| !title.is_empty());
+
+            Some(Feed { url, title, kind })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use url::Url;
+
+    use crate::html_from_bytes;
+    use crate::tests::{BASE_ELEMENT_HTML, FEEDS_HTML};
+
+    use super::{find_feeds, FeedKind};
+
+    #[test]
+    fn finds_feeds_with_titles() {
+        let html = html_from_bytes(FEEDS_HTML).unwrap();
+        let base = Url::parse("https://dailycrumb.example.com/").unwrap();
+        let feeds = find_feeds(&html, Some(&base));
+
+        assert_eq!(feeds.len(), 3);
+        assert_eq!(
+            feeds[0].url.as_str(),
+            "https://dailycrumb.example.com/feed/"
+        );
+        assert_eq!(feeds[0].title.as_deref(), Some("The Daily Crumb » Feed"));
+        assert_eq!(feeds[0].kind, FeedKind::Rss);
+        assert_eq!(
+            feeds[1].url.as_str(),
+            "https://dailycrumb.example.com/comments/feed/"
+        );
+        assert_eq!(
+            feeds[1].title.as_deref(),
+            Some("The Daily Crumb » Comments Feed")
+        );
+        assert_eq!(feeds[2].title, None);
+        assert_eq!(feeds[2].kind, FeedKind::Atom);
+    }
+
+    #[test]
+    fn skips_relative_feeds_without_base() {
+        let html = html_from_bytes(FEEDS_HTML).unwrap();
+        let feeds = find_feeds(&html, None);
+
+        assert_eq!(feeds.len(), 2);
+        assert!(feeds
+            .iter()
+            .all(|feed| feed.url.path() != "/comments/feed/"));
+    }
+
+    #[test]
+    fn resolves_feeds_against_base_element() {
+        let html = html_from_bytes(BASE_ELEMENT_HTML).unwrap();
+        let feeds = find_feeds(&html, None);
+
+        assert_eq!(feeds.len(), 1);
+        assert_eq!(
+            feeds[0].url.as_str(),
+            "https://cdn.example.com/assets/feed.xml"
+        );
+    }
+}
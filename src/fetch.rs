@@ -1,8 +1,31 @@
+use std::cell::Cell;
+use std::fmt;
+use std::net::{IpAddr, SocketAddr};
+use std::rc::Rc;
 use std::string::FromUtf8Error;
+use std::sync::Arc;
+use std::time::Duration;
 
-use reqwest::get;
+use futures_util::stream::{self, Stream, StreamExt};
+
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use reqwest::header::{HeaderMap, HeaderName, ACCEPT, CONTENT_TYPE, ETAG, LAST_MODIFIED, LOCATION};
+use reqwest::redirect::Policy;
+use reqwest::{Client, RequestBuilder, Response};
 use scraper::Html;
 use thiserror::Error;
+use tokio::net::lookup_host;
+use tokio::sync::Semaphore;
+use tokio::time::{sleep_until, Instant};
+use url::{Host, Url};
+
+use crate::html::document_base_url;
+use crate::preview::html_from_bytes_with_charset;
+use crate::preview::Error as PreviewError;
+use crate::profiles::registry::ProfileRegistry;
+use crate::providers::oembed::complete_with_oembed;
+use crate::urls::{is_internal_ip, resolve_url};
+use crate::{html_from_bytes, ExtractionConfig, LinkPreview};
 
 #[cfg(feature = "blocking")]
 use reqwest::blocking::get as blocking_get;
@@ -20,19 +43,782 @@ pub enum Error {
     StreamError(String, reqwest::Error),
     #[error("Failed to parse bytes into UTF-8 while streaming response from {0}")]
     InvalidUtf8(String, FromUtf8Error),
+    #[error("Failed to build the HTTP client. An error ocurred: {0}")]
+    ClientError(reqwest::Error),
+    #[error("Failed to read the HTML document from {0}. An error ocurred: {1}")]
+    InvalidHtml(String, PreviewError),
+    #[error("Failed to fetch {0}. The bytes read exceeded the limit of {1} bytes")]
+    BodyTooLarge(String, usize),
+    #[error("Failed to fetch {0}. Too many redirects were followed")]
+    TooManyRedirects(String),
+    #[error("Refused to fetch {0}. The host is not allowed")]
+    BlockedHost(String),
+    #[error("Failed to fetch {0}. The request timed out")]
+    Timeout(String),
+    #[error("Refused to parse {0}. The content type {1} is not supported")]
+    UnsupportedContentType(String, String),
+    #[error("Failed to parse the oEmbed response from {0}")]
+    InvalidOEmbed(String),
+}
+
+/// HTTP protocol version used to fetch pages
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HttpVersion {
+    /// Only speak HTTP/1.1
+    Http1,
+    /// Speak HTTP/2 right away, without negotiating it with the server first
+    /// (prior knowledge)
+    Http2,
+}
+
+/// Default time allowed for a fetch to complete
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Default maximum amount of redirects followed
+const DEFAULT_MAX_REDIRECTS: usize = 10;
+
+/// Default maximum size of a response body in bytes (5 MiB)
+const DEFAULT_MAX_BODY_BYTES: usize = 5 * 1024 * 1024;
+
+/// Media types of the responses parsed by default
+const DEFAULT_ALLOW_CONTENT_TYPES: [&str; 2] = ["text/html", "application/xhtml+xml"];
+
+/// Default `Accept` header sent with every request
+const DEFAULT_ACCEPT: &str = "text/html,application/xhtml+xml";
+
+/// Default `User-Agent` sent with every request
+const DEFAULT_USER_AGENT: &str = concat!(
+    "Mozilla/5.0 (compatible; link-preview/",
+    env!("CARGO_PKG_VERSION"),
+    ")"
+);
+
+/// Callback deciding whether a host may be fetched, see
+/// `FetchOptions::allowed_host_filter`
+#[derive(Clone)]
+pub struct HostFilter(Arc<dyn Fn(&str) -> bool + Send + Sync>);
+
+impl HostFilter {
+    /// Creates a filter allowing the hosts for which `filter` returns `true`
+    pub fn new(filter: impl Fn(&str) -> bool + Send + Sync + 'static) -> Self {
+        HostFilter(Arc::new(filter))
+    }
+
+    /// Whether `host` may be fetched
+    pub fn allows(&self, host: &str) -> bool {
+        (self.0)(host)
+    }
+}
+
+impl fmt::Debug for HostFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("HostFilter")
+    }
+}
+
+/// Options for the HTTP client used to fetch pages
+#[derive(Clone, Debug)]
+pub struct FetchOptions {
+    /// Forces a specific HTTP protocol version, which helps reproducing
+    /// results from servers behaving differently on each of them. When
+    /// `None` the version is negotiated with the server.
+    pub http_version: Option<HttpVersion>,
+    /// Maximum amount of bytes read across every response of a fetch,
+    /// including the bodies of redirect responses. Once exceeded the fetch is
+    /// aborted with `Error::BodyTooLarge`. When `None` there is no limit.
+    pub total_byte_budget: Option<usize>,
+    /// Minimum time between requests sent to the same host by `fetch_many`.
+    /// Defaults to no delay.
+    pub per_host_delay: Duration,
+    /// Refuses to fetch URLs whose host is, or resolves to, an internal
+    /// address such as a loopback, private or link-local one (see
+    /// `is_internal_ip`) with `Error::BlockedHost`, unless the host is listed
+    /// in `allowed_hosts`. Every redirect is checked as well.
+    ///
+    /// Domains are checked by the resolver the client connects through, so
+    /// the addresses checked are the ones connected to, and internal ones are
+    /// never dialed even when a domain also resolves to public ones. Domains
+    /// which fail to resolve fail the fetch.
+    ///
+    /// Use this when fetching user provided URLs to prevent Server-Side
+    /// Request Forgery (SSRF).
+    pub block_private_hosts: bool,
+    /// When present, only URLs whose host is listed (compared
+    /// case-insensitively) are fetched, others fail with
+    /// `Error::BlockedHost`. Listed hosts are exempt from
+    /// `block_private_hosts`.
+    pub allowed_hosts: Option<Vec<String>>,
+    /// When present, only URLs whose host the filter allows are fetched,
+    /// others fail with `Error::BlockedHost`. Every redirect is checked as
+    /// well.
+    ///
+    /// Unlike `allowed_hosts`, hosts allowed by the filter are still subject
+    /// to `block_private_hosts`.
+    pub allowed_host_filter: Option<HostFilter>,
+    /// Time allowed for each request, from connecting until its body is
+    /// read, after which it fails with `Error::Timeout`. Defaults to 10
+    /// seconds.
+    pub timeout: Duration,
+    /// Time allowed for establishing the connection of each request, after
+    /// which it fails with `Error::Timeout`. This allows failing fast on
+    /// unreachable hosts while `timeout` still leaves slow servers time to
+    /// send their body. When `None`, only `timeout` applies.
+    pub connect_timeout: Option<Duration>,
+    /// Maximum amount of redirects followed, after which the fetch fails
+    /// with `Error::TooManyRedirects`. Defaults to 10.
+    pub max_redirects: usize,
+    /// Maximum size of a response body in bytes. The body is streamed and
+    /// the fetch is aborted with `Error::BodyTooLarge` as soon as it's
+    /// exceeded, or right away when the declared `Content-Length` exceeds it.
+    /// Defaults to 5 MiB.
+    pub max_body_bytes: usize,
+    /// Media types (compared case-insensitively and ignoring parameters such
+    /// as `charset`) of the responses parsed. Responses declaring any other
+    /// `Content-Type` fail with `Error::UnsupportedContentType` before their
+    /// body is read, while responses declaring none are parsed. An empty list
+    /// allows every type.
+    ///
+    /// Defaults to `text/html` and `application/xhtml+xml`.
+    pub allow_content_types: Vec<String>,
+    /// `User-Agent` sent with every request.
+    ///
+    /// Some sites, notably news outlets and CDNs, answer unknown clients with
+    /// a stripped page or a `403`, leaving out the OpenGraph and Twitter tags
+    /// previews are built from. A browser-like value gets the same page
+    /// browsers and social networks' crawlers are served.
+    ///
+    /// Defaults to `Mozilla/5.0 (compatible; link-preview/<version>)`.
+    pub user_agent: String,
+    /// `Accept` header sent with every request.
+    ///
+    /// Servers negotiating the content of their responses may answer a
+    /// permissive value with JSON rather than the HTML document previews are
+    /// extracted from.
+    ///
+    /// Defaults to `text/html,application/xhtml+xml`.
+    pub accept: String,
+}
+
+impl Default for FetchOptions {
+    fn default() -> Self {
+        FetchOptions {
+            http_version: None,
+            total_byte_budget: None,
+            per_host_delay: Duration::ZERO,
+            block_private_hosts: false,
+            allowed_hosts: None,
+            allowed_host_filter: None,
+            timeout: DEFAULT_TIMEOUT,
+            connect_timeout: None,
+            max_redirects: DEFAULT_MAX_REDIRECTS,
+            max_body_bytes: DEFAULT_MAX_BODY_BYTES,
+            allow_content_types: DEFAULT_ALLOW_CONTENT_TYPES
+                .iter()
+                .map(|content_type| content_type.to_string())
+                .collect(),
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            accept: DEFAULT_ACCEPT.to_string(),
+        }
+    }
+}
+
+impl FetchOptions {
+    /// Creates the default options sending the provided `User-Agent`
+    pub fn with_user_agent(user_agent: impl Into<String>) -> Self {
+        FetchOptions {
+            user_agent: user_agent.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Builds a `Client` configured after these options
+    fn client(&self) -> Result<Client, Error> {
+        let mut builder = Client::builder()
+            .timeout(self.timeout)
+            .user_agent(self.user_agent.as_str());
+
+        if let Some(connect_timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+
+        match self.http_version {
+            Some(HttpVersion::Http1) => builder = builder.http1_only(),
+            Some(HttpVersion::Http2) => builder = builder.http2_prior_knowledge(),
+            None => {}
+        }
+
+        // Redirects are followed by `send` so their bodies count against the
+        // byte budget, their hosts are checked and `max_redirects` is
+        // enforced the same way whatever the `reqwest` version
+        builder = builder.redirect(Policy::none());
+
+        if self.block_private_hosts {
+            builder = builder.dns_resolver(Arc::new(PublicResolver {
+                allowed_hosts: self.allowed_hosts.clone().unwrap_or_default(),
+            }));
+        }
+
+        builder.build().map_err(Error::ClientError)
+    }
+
+    /// Builds a `GET` request to `url` sending the headers configured by these
+    /// options
+    fn get(&self, client: &Client, url: &str) -> RequestBuilder {
+        client.get(url).header(ACCEPT, self.accept.as_str())
+    }
+
+    /// Ensures the `Content-Type` of the response to `url` is listed in
+    /// `allow_content_types`
+    fn check_content_type(&self, url: &str, resp: &Response) -> Result<(), Error> {
+        let Some(content_type) = resp
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+        else {
+            return Ok(());
+        };
+        let media_type = content_type.split(';').next().unwrap_or_default().trim();
+
+        if self.allow_content_types.is_empty()
+            || self
+                .allow_content_types
+                .iter()
+                .any(|allowed| allowed.trim().eq_ignore_ascii_case(media_type))
+        {
+            return Ok(());
+        }
+
+        Err(Error::UnsupportedContentType(
+            url.to_string(),
+            content_type.to_string(),
+        ))
+    }
+
+    /// Ensures the host of `url` may be fetched according to `allowed_hosts`,
+    /// `allowed_host_filter` and `block_private_hosts`.
+    ///
+    /// Only IP addresses are checked against `block_private_hosts` here,
+    /// domains are checked by `PublicResolver` when connecting.
+    fn check_host(&self, url: &str) -> Result<(), Error> {
+        let blocked = || Error::BlockedHost(url.to_string());
+        let Some(host) = Url::parse(url)
+            .ok()
+            .and_then(|url| url.host().map(|host| host.to_owned()))
+        else {
+            return Err(blocked());
+        };
+        let name = host.to_string();
+
+        if let Some(filter) = &self.allowed_host_filter {
+            if !filter.allows(&name) {
+                return Err(blocked());
+            }
+        }
+
+        if let Some(allowed_hosts) = &self.allowed_hosts {
+            if !allowed_hosts
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(&name))
+            {
+                return Err(blocked());
+            }
+
+            return Ok(());
+        }
+
+        let ip = match host {
+            Host::Ipv4(ip) => IpAddr::V4(ip),
+            Host::Ipv6(ip) => IpAddr::V6(ip),
+            Host::Domain(_) => return Ok(()),
+        };
+
+        if self.block_private_hosts && is_internal_ip(ip) {
+            return Err(blocked());
+        }
+
+        Ok(())
+    }
+}
+
+/// Resolver used when `block_private_hosts` is set, leaving out internal
+/// addresses so they are never connected to, regardless of what a domain
+/// resolved to when it was checked before (DNS rebinding)
+struct PublicResolver {
+    /// Hosts exempt from the check, see `FetchOptions::allowed_hosts`
+    allowed_hosts: Vec<String>,
+}
+
+/// Error raised by `PublicResolver` when a domain only resolves to internal
+/// addresses, reported as `Error::BlockedHost`
+#[derive(Error, Debug)]
+#[error("{0} only resolves to internal addresses")]
+struct InternalAddressError(String);
+
+impl Resolve for PublicResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let exempt = self
+            .allowed_hosts
+            .iter()
+            .any(|allowed| allowed.eq_ignore_ascii_case(name.as_str()));
+
+        Box::pin(resolve_public(name, exempt))
+    }
+}
+
+/// Resolves `name` leaving out internal addresses, unless it's `exempt`
+async fn resolve_public(
+    name: Name,
+    exempt: bool,
+) -> Result<Addrs, Box<dyn std::error::Error + Send + Sync>> {
+    // The port is replaced by the one of the URL when connecting
+    let addresses: Vec<SocketAddr> = lookup_host((name.as_str(), 0))
+        .await?
+        .filter(|address| exempt || !is_internal_ip(address.ip()))
+        .collect();
+
+    if addresses.is_empty() {
+        return Err(InternalAddressError(name.as_str().to_string()).into());
+    }
+
+    Ok(Box::new(addresses.into_iter()))
+}
+
+/// Response headers preserved in `FetchResult::headers`
+const DETAILED_HEADERS: [HeaderName; 3] = [CONTENT_TYPE, LAST_MODIFIED, ETAG];
+
+/// A `LinkPreview` along with details on the HTTP response it was built from
+#[derive(Clone, Debug)]
+pub struct FetchResult {
+    pub preview: LinkPreview,
+    /// Status code of the final response
+    pub status: u16,
+    /// Subset of the response headers: `content-type`, `last-modified` and
+    /// `etag`, when present
+    pub headers: HeaderMap,
+    /// URL of the final response, after redirects are followed
+    pub final_url: Url,
+}
+
+/// An HTML document along with the URL it was retrieved from
+#[derive(Clone, Debug)]
+pub struct FetchedPage {
+    /// URL of the final response, after redirects are followed
+    pub final_url: Url,
+    pub html: Html,
+}
+
+impl FetchedPage {
+    /// Creates a `LinkPreview` from the document, resolving relative URLs
+    /// against `final_url` and falling back to its domain, same as
+    /// `preview_from_bytes`
+    pub fn preview(&self) -> LinkPreview {
+        preview_from_html(&self.html, &self.final_url)
+    }
+}
+
+/// Tracks the bytes read across every response of a fetch against
+/// `FetchOptions::total_byte_budget`
+struct ByteBudget {
+    limit: Option<usize>,
+    read: usize,
+}
+
+impl ByteBudget {
+    fn new(limit: Option<usize>) -> Self {
+        ByteBudget { limit, read: 0 }
+    }
+
+    /// Accounts for `bytes` read from `url`, failing once the limit is
+    /// exceeded
+    fn consume(&mut self, url: &str, bytes: usize) -> Result<(), Error> {
+        self.read += bytes;
+
+        match self.limit {
+            Some(limit) if self.read > limit => Err(Error::BodyTooLarge(url.to_string(), limit)),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Sends a `GET` request to the provided URL using a client configured after
+/// `options`.
+///
+/// Redirects are followed here instead of by the client, reading the body of
+/// every redirect response into `budget` and checking the host of every URL
+/// requested.
+async fn send(
+    url: &str,
+    options: &FetchOptions,
+    budget: &mut ByteBudget,
+) -> Result<Response, Error> {
+    let client = options.client()?;
+    let mut current = url.to_string();
+
+    for _ in 0..=options.max_redirects {
+        options.check_host(&current)?;
+
+        let resp = options
+            .get(&client, &current)
+            .send()
+            .await
+            .map_err(|err| request_error(&current, err, Error::FetchFailed))?;
+        let location = resp
+            .status()
+            .is_redirection()
+            .then(|| resp.headers().get(LOCATION))
+            .flatten()
+            .and_then(|location| location.to_str().ok())
+            .and_then(|location| resp.url().join(location).ok());
+        let Some(location) = location else {
+            return Ok(resp);
+        };
+
+        read_body(resp, &current, options.max_body_bytes, budget).await?;
+        current = location.to_string();
+    }
+
+    Err(Error::TooManyRedirects(url.to_string()))
+}
+
+/// Maps an error raised by `reqwest` while fetching `url` to
+/// `Error::Timeout` when the request timed out, to `Error::BlockedHost` when
+/// the host only resolved to internal addresses, or through `wrap` otherwise
+fn request_error(
+    url: &str,
+    err: reqwest::Error,
+    wrap: fn(String, reqwest::Error) -> Error,
+) -> Error {
+    let mut source = std::error::Error::source(&err);
+
+    while let Some(cause) = source {
+        if cause.is::<InternalAddressError>() {
+            return Error::BlockedHost(url.to_string());
+        }
+
+        source = cause.source();
+    }
+
+    if err.is_timeout() {
+        return Error::Timeout(url.to_string());
+    }
+
+    wrap(url.to_string(), err)
+}
+
+/// Reads the whole body of the provided response into `budget`, failing once
+/// it exceeds `max_body_bytes`
+async fn read_body(
+    mut resp: Response,
+    url: &str,
+    max_body_bytes: usize,
+    budget: &mut ByteBudget,
+) -> Result<Vec<u8>, Error> {
+    let too_large = || Error::BodyTooLarge(url.to_string(), max_body_bytes);
+
+    if resp
+        .content_length()
+        .is_some_and(|length| length > max_body_bytes as u64)
+    {
+        return Err(too_large());
+    }
+
+    let mut bytes: Vec<u8> = Vec::new();
+
+    while let Some(chunk) = resp
+        .chunk()
+        .await
+        .map_err(|err| request_error(url, err, Error::ParseError))?
+    {
+        budget.consume(url, chunk.len())?;
+
+        if bytes.len() + chunk.len() > max_body_bytes {
+            return Err(too_large());
+        }
+
+        bytes.extend_from_slice(&chunk);
+    }
+
+    Ok(bytes)
 }
 
 /// Fetches the provided URL and retrieves an instance of `Html`
 pub async fn fetch(url: &str) -> Result<Html, Error> {
-    let resp = get(url)
-        .await
-        .map_err(|err| Error::FetchFailed(url.to_string(), err))?;
-    let html = resp
-        .text()
+    fetch_with_options(url, &FetchOptions::default()).await
+}
+
+/// Fetches the provided URL using the provided `FetchOptions` and retrieves
+/// an instance of `Html`
+pub async fn fetch_with_options(url: &str, options: &FetchOptions) -> Result<Html, Error> {
+    fetch_page_with_options(url, options)
         .await
-        .map_err(|err| Error::ParseError(url.to_string(), err))?;
+        .map(|page| page.html)
+}
 
-    Ok(Html::parse_document(&html))
+/// Fetches the provided URL and retrieves an instance of `FetchedPage`
+pub async fn fetch_page(url: &str) -> Result<FetchedPage, Error> {
+    fetch_page_with_options(url, &FetchOptions::default()).await
+}
+
+/// Fetches the provided URL using the provided `FetchOptions` and retrieves
+/// an instance of `FetchedPage`, holding the URL of the final response once
+/// redirects are followed
+pub async fn fetch_page_with_options(
+    url: &str,
+    options: &FetchOptions,
+) -> Result<FetchedPage, Error> {
+    let mut budget = ByteBudget::new(options.total_byte_budget);
+    let resp = send(url, options, &mut budget).await?;
+
+    options.check_content_type(url, &resp)?;
+
+    let final_url = resp.url().clone();
+    let charset = content_type_charset(&resp);
+    let bytes = read_body(resp, url, options.max_body_bytes, &mut budget).await?;
+    let html = parse_body(&bytes, charset.as_deref());
+
+    Ok(FetchedPage { final_url, html })
+}
+
+/// Parses the body of a response, decoded in the encoding labeled by
+/// `charset` or declared by the document itself (see `html_from_bytes`), or
+/// else as UTF-8 replacing malformed sequences
+fn parse_body(bytes: &[u8], charset: Option<&str>) -> Html {
+    html_from_bytes_with_charset(bytes, charset)
+        .unwrap_or_else(|_| Html::parse_document(&String::from_utf8_lossy(bytes)))
+}
+
+/// Retrieves the `charset` parameter of the `Content-Type` of the provided
+/// response, unquoted
+fn content_type_charset(resp: &Response) -> Option<String> {
+    let content_type = resp.headers().get(CONTENT_TYPE)?.to_str().ok()?;
+
+    content_type.split(';').skip(1).find_map(|param| {
+        let (name, value) = param.split_once('=')?;
+
+        name.trim()
+            .eq_ignore_ascii_case("charset")
+            .then(|| value.trim().trim_matches('"').to_string())
+    })
+}
+
+/// Fetches the provided URL using the provided `FetchOptions` and retrieves
+/// the body of the response regardless of its `Content-Type`
+pub(crate) async fn fetch_bytes(url: &str, options: &FetchOptions) -> Result<Vec<u8>, Error> {
+    let mut budget = ByteBudget::new(options.total_byte_budget);
+    let resp = send(url, options, &mut budget).await?;
+
+    read_body(resp, url, options.max_body_bytes, &mut budget).await
+}
+
+/// Fetches the provided URL and retrieves its `LinkPreview`, extracted the
+/// same way `fetch_detailed` does
+pub async fn fetch_preview(url: &str) -> Result<LinkPreview, Error> {
+    fetch_detailed(url).await.map(|result| result.preview)
+}
+
+/// URLs sharing a host, if any, along with their index in the URLs fetched
+/// by `fetch_many`
+type HostQueue<'a> = (Option<String>, Vec<(usize, &'a str)>);
+
+/// Fetches every URL in `urls` using the provided `FetchOptions`, running up
+/// to `concurrency` requests at a time, and retrieves the results in the same
+/// order as `urls`.
+///
+/// Requests to the same host are sent in the order of `urls` and spaced by
+/// `FetchOptions::per_host_delay`, while requests to different hosts proceed
+/// in parallel. Requests waiting for their host's delay don't count against
+/// `concurrency`, so they never hold back requests to other hosts.
+pub async fn fetch_many(
+    urls: &[&str],
+    concurrency: usize,
+    options: &FetchOptions,
+) -> Vec<Result<Html, Error>> {
+    let permits = Semaphore::new(concurrency.max(1));
+    let mut hosts: Vec<HostQueue> = Vec::new();
+
+    for (index, url) in urls.iter().enumerate() {
+        let host = Url::parse(url)
+            .ok()
+            .and_then(|url| url.host_str().map(|host| host.to_string()));
+
+        match hosts
+            .iter_mut()
+            .find(|(other, _)| host.is_some() && *other == host)
+        {
+            Some((_, urls)) => urls.push((index, url)),
+            None => hosts.push((host, vec![(index, url)])),
+        }
+    }
+
+    let streams = hosts.into_iter().map(|(_, urls)| {
+        let permits = &permits;
+        let last_sent: Rc<Cell<Option<Instant>>> = Rc::new(Cell::new(None));
+
+        // Each request waits for the previous one to the same host to be sent
+        // before waiting for its turn, and only then for a permit
+        stream::iter(urls)
+            .then(move |(index, url)| {
+                let last_sent = Rc::clone(&last_sent);
+
+                async move {
+                    if let Some(last) = last_sent.get() {
+                        sleep_until(last + options.per_host_delay).await;
+                    }
+
+                    let permit = permits
+                        .acquire()
+                        .await
+                        .expect("the semaphore is never closed");
+
+                    last_sent.set(Some(Instant::now()));
+                    (index, url, permit)
+                }
+            })
+            .map(|(index, url, permit)| async move {
+                let result = fetch_with_options(url, options).await;
+
+                drop(permit);
+                (index, result)
+            })
+            .buffer_unordered(usize::MAX)
+            .boxed_local()
+    });
+    let mut results: Vec<(usize, Result<Html, Error>)> =
+        stream::select_all(streams).collect().await;
+
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Fetches every URL yielded by `urls`, running up to `concurrency` requests
+/// at a time, and yields each URL along with its `LinkPreview` as soon as it
+/// is retrieved, so results can be processed incrementally.
+///
+/// Results are yielded in completion order rather than in the order of
+/// `urls`.
+pub fn fetch_stream(
+    urls: impl Stream<Item = Url>,
+    concurrency: usize,
+) -> impl Stream<Item = (Url, Result<LinkPreview, Error>)> {
+    urls.map(|url| async move {
+        let preview = fetch_preview(url.as_str()).await;
+
+        (url, preview)
+    })
+    .buffer_unordered(concurrency.max(1))
+}
+
+/// Same as `fetch_stream` but fetches every URL using the provided
+/// `FetchOptions`
+pub fn fetch_stream_with_options<'a>(
+    urls: impl Stream<Item = Url> + 'a,
+    concurrency: usize,
+    options: &'a FetchOptions,
+) -> impl Stream<Item = (Url, Result<LinkPreview, Error>)> + 'a {
+    urls.map(move |url| async move {
+        let preview = fetch_detailed_with_options(url.as_str(), options)
+            .await
+            .map(|result| result.preview);
+
+        (url, preview)
+    })
+    .buffer_unordered(concurrency.max(1))
+}
+
+/// Fetches the provided URL and retrieves an instance of `FetchResult`
+pub async fn fetch_detailed(url: &str) -> Result<FetchResult, Error> {
+    fetch_detailed_with_options(url, &FetchOptions::default()).await
+}
+
+/// Fetches the provided URL using the provided `FetchOptions` and retrieves
+/// an instance of `FetchResult`
+pub async fn fetch_detailed_with_options(
+    url: &str,
+    options: &FetchOptions,
+) -> Result<FetchResult, Error> {
+    let mut budget = ByteBudget::new(options.total_byte_budget);
+    let resp = send(url, options, &mut budget).await?;
+
+    options.check_content_type(url, &resp)?;
+
+    let status = resp.status().as_u16();
+    let final_url = resp.url().clone();
+    let mut headers = HeaderMap::new();
+
+    for name in DETAILED_HEADERS {
+        if let Some(value) = resp.headers().get(&name) {
+            let value = value.clone();
+            headers.insert(name, value);
+        }
+    }
+
+    let charset = content_type_charset(&resp);
+    let bytes = read_body(resp, url, options.max_body_bytes, &mut budget).await?;
+    // `Html` isn't `Send`, so it's dropped before awaiting the oEmbed response
+    let (mut preview, oembed_endpoint) = {
+        let html = parse_body(&bytes, charset.as_deref());
+
+        preview_with_oembed_endpoint(&html, &final_url)
+    };
+
+    if let Some(endpoint) = oembed_endpoint {
+        preview = complete_with_oembed(preview, &endpoint, options).await;
+    }
+
+    Ok(FetchResult {
+        preview,
+        status,
+        headers,
+        final_url,
+    })
+}
+
+/// Creates a `LinkPreview` from an HTML document already retrieved from `url`
+/// without any network access.
+///
+/// This is the same flow `fetch_detailed` follows once the document is
+/// downloaded: the bytes are decoded, the first profile fitting either `url`
+/// or the document is used for extraction, relative URLs are resolved
+/// against `url` and the `domain` falls back to `url`'s.
+pub fn preview_from_bytes(bytes: &[u8], url: &Url) -> Result<LinkPreview, Error> {
+    let html = html_from_bytes(bytes).map_err(|err| Error::InvalidHtml(url.to_string(), err))?;
+
+    Ok(preview_from_html(&html, url))
+}
+
+/// Creates a `LinkPreview` from an HTML document retrieved from `url`, using
+/// the first profile fitting either of them
+fn preview_from_html(html: &Html, url: &Url) -> LinkPreview {
+    preview_with_oembed_endpoint(html, url).0
+}
+
+/// Same as `preview_from_html`, along with the oEmbed endpoint the profile
+/// used completes the `LinkPreview` with (`ProfileExt::oembed_endpoint`)
+fn preview_with_oembed_endpoint(html: &Html, url: &Url) -> (LinkPreview, Option<Url>) {
+    let config = ExtractionConfig {
+        base_url: Some(url.clone()),
+        ..Default::default()
+    };
+    let (mut preview, oembed_endpoint) = ProfileRegistry::default()
+        .extract_with_oembed_endpoint(url, html)
+        .unwrap_or_else(|| (LinkPreview::from_html_with_config(html, &config), None));
+
+    // Profiles extract without a base URL, dropping relative images and
+    // favicons, so these are looked up again against `url`
+    if preview.image_url.is_none() {
+        preview.image_url = LinkPreview::find_first_image_url_with_config(html, &config);
+    }
+
+    if preview.favicon.is_none() {
+        preview.favicon = LinkPreview::find_first_favicon(html)
+            .and_then(|favicon| resolve_url(&favicon, document_base_url(html, Some(url)).as_ref()));
+    }
+
+    if preview.domain.is_none() {
+        preview.domain = url.domain().map(|domain| domain.to_string());
+    }
+
+    (preview, oembed_endpoint)
 }
 
 #[cfg(feature = "blocking")]
@@ -55,16 +841,15 @@ pub async fn fetch_partially(url: &str) -> Result<Html, Error> {
 #[cfg(feature = "stream")]
 pub async fn fetch_with_limit(url: &str, limit: usize) -> Result<Html, Error> {
     let mut laps = 0_usize;
-    let mut resp = get(url)
-        .await
-        .map_err(|err| Error::FetchFailed(url.to_string(), err))?
+    let mut resp = send(url, &FetchOptions::default(), &mut ByteBudget::new(None))
+        .await?
         .bytes_stream();
     let mut bytes: Vec<u8> = Vec::new();
 
     while let Some(chunk) = resp
         .try_next()
         .await
-        .map_err(|err| Error::StreamError(url.to_string(), err))?
+        .map_err(|err| request_error(url, err, Error::StreamError))?
     {
         if laps >= limit {
             break;
@@ -83,10 +868,25 @@ pub async fn fetch_with_limit(url: &str, limit: usize) -> Result<Html, Error> {
 
 #[cfg(test)]
 mod tests {
-    use crate::tests::REMOTE_FULL_FEATURED_HTML;
+    use std::net::{SocketAddr, TcpStream};
+    use std::time::{Duration, Instant};
+
+    use futures_util::stream::{self, StreamExt};
+    use reqwest::header::{CONTENT_TYPE, ETAG, LAST_MODIFIED};
+    use tokio::net::TcpSocket;
+    use url::Url;
+
+    use crate::mock::{MockResponse, MockServer};
+    use crate::tests::{
+        OG_COMPLIANT_HTML, RELATIVE_URLS_HTML, REMOTE_FULL_FEATURED_HTML, YOUTUBE_VIDEO_HTML,
+    };
     use crate::LinkPreview;
 
-    use super::{fetch, fetch_partially, fetch_with_limit};
+    use super::{
+        fetch, fetch_detailed, fetch_detailed_with_options, fetch_many, fetch_page_with_options,
+        fetch_partially, fetch_preview, fetch_stream, fetch_stream_with_options, fetch_with_limit,
+        fetch_with_options, preview_from_bytes, Error, FetchOptions, HostFilter, HttpVersion,
+    };
 
     #[tokio::test]
     async fn fetches() {
@@ -100,6 +900,17 @@ mod tests {
         assert_eq!(link_preview.description.unwrap_or(String::default()), "John Appleseed tells you his secrets on SEO for a better web experience by taking advantage of OpenGraph\'s Tags!");
     }
 
+    #[tokio::test]
+    async fn fetches_preview() {
+        let link_preview = fetch_preview(REMOTE_FULL_FEATURED_HTML).await.unwrap();
+
+        assert_eq!(
+            link_preview.title.unwrap(),
+            "SEO Strategies for a better web"
+        );
+        assert_eq!(link_preview.description.unwrap(), "John Appleseed tells you his secrets on SEO for a better web experience by taking advantage of OpenGraph\'s Tags!");
+    }
+
     #[tokio::test]
     async fn fetches_page_partially() {
         let html = fetch_partially(REMOTE_FULL_FEATURED_HTML).await.unwrap();
@@ -125,4 +936,771 @@ mod tests {
         );
         assert_eq!(link_preview.description.unwrap_or(String::default()), "John Appleseed tells you his secrets on SEO for a better web experience by taking advantage of OpenGraph\'s Tags!");
     }
+
+    #[tokio::test]
+    async fn fetches_detailed_result() {
+        let server = MockServer::start(vec![(
+            "/",
+            MockResponse::html(OG_COMPLIANT_HTML)
+                .header("ETag", "\"33a64df5\"")
+                .header("Last-Modified", "Wed, 21 Oct 2015 07:28:00 GMT")
+                .header("X-Powered-By", "mock"),
+        )]);
+        let result = fetch_detailed(&server.url("/")).await.unwrap();
+
+        assert_eq!(result.status, 200);
+        assert_eq!(result.final_url.as_str(), server.url("/"));
+        assert_eq!(
+            result.headers.get(CONTENT_TYPE).unwrap(),
+            "text/html; charset=utf-8"
+        );
+        assert_eq!(result.headers.get(ETAG).unwrap(), "\"33a64df5\"");
+        assert_eq!(
+            result.headers.get(LAST_MODIFIED).unwrap(),
+            "Wed, 21 Oct 2015 07:28:00 GMT"
+        );
+        assert!(result.headers.get("x-powered-by").is_none());
+        assert_eq!(
+            result.preview.title.unwrap(),
+            "SEO Strategies for a better web"
+        );
+    }
+
+    #[tokio::test]
+    async fn forces_http1() {
+        let server = MockServer::start(vec![("/", MockResponse::html(OG_COMPLIANT_HTML))]);
+        let options = FetchOptions {
+            http_version: Some(HttpVersion::Http1),
+            ..Default::default()
+        };
+        let html = fetch_with_options(&server.url("/"), &options)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            LinkPreview::from(&html).title.unwrap(),
+            "SEO Strategies for a better web"
+        );
+        assert!(server.requests()[0].starts_with("GET / HTTP/1.1"));
+    }
+
+    #[tokio::test]
+    async fn forces_http2_prior_knowledge() {
+        let server = MockServer::start(vec![("/", MockResponse::html(OG_COMPLIANT_HTML))]);
+        let options = FetchOptions {
+            http_version: Some(HttpVersion::Http2),
+            ..Default::default()
+        };
+        let result = fetch_with_options(&server.url("/"), &options).await;
+
+        // The mock server only speaks HTTP/1.1, so it can't make sense of the
+        // HTTP/2 connection preface sent by the client
+        assert!(result.is_err());
+        assert!(server.requests()[0].starts_with("PRI * HTTP/2.0"));
+    }
+
+    #[tokio::test]
+    async fn aborts_when_redirect_chain_exceeds_byte_budget() {
+        let server = MockServer::start(vec![
+            ("/", MockResponse::redirect("/hop").body(vec![b'x'; 600])),
+            (
+                "/hop",
+                MockResponse::redirect("/page").body(vec![b'x'; 600]),
+            ),
+            ("/page", MockResponse::html(OG_COMPLIANT_HTML)),
+        ]);
+        let options = FetchOptions {
+            total_byte_budget: Some(1000),
+            ..Default::default()
+        };
+        let result = fetch_with_options(&server.url("/"), &options).await;
+
+        assert!(matches!(result, Err(Error::BodyTooLarge(_, 1000))));
+        // The chain is aborted before the final page is requested
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn follows_redirects_within_byte_budget() {
+        let server = MockServer::start(vec![
+            ("/", MockResponse::redirect("/page").body(vec![b'x'; 600])),
+            ("/page", MockResponse::html(OG_COMPLIANT_HTML)),
+        ]);
+        let options = FetchOptions {
+            total_byte_budget: Some(64 * 1024),
+            ..Default::default()
+        };
+        let result = fetch_detailed_with_options(&server.url("/"), &options)
+            .await
+            .unwrap();
+
+        assert_eq!(result.final_url.as_str(), server.url("/page"));
+        assert_eq!(
+            result.preview.title.unwrap(),
+            "SEO Strategies for a better web"
+        );
+    }
+
+    #[tokio::test]
+    async fn aborts_when_body_exceeds_max_body_bytes() {
+        let server = MockServer::start(vec![
+            ("/large", MockResponse::html(vec![b'x'; 2 * 1024 * 1024])),
+            ("/small", MockResponse::html(OG_COMPLIANT_HTML)),
+        ]);
+        let options = FetchOptions {
+            max_body_bytes: 1024 * 1024,
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            fetch_with_options(&server.url("/large"), &options).await,
+            Err(Error::BodyTooLarge(_, 1_048_576))
+        ));
+        assert!(fetch_with_options(&server.url("/small"), &options)
+            .await
+            .is_ok());
+        assert_eq!(FetchOptions::default().max_body_bytes, 5 * 1024 * 1024);
+    }
+
+    #[tokio::test]
+    async fn refuses_unsupported_content_types() {
+        let server = MockServer::start(vec![
+            (
+                "/report.pdf",
+                MockResponse::new(200)
+                    .header("Content-Type", "application/pdf")
+                    .body(b"%PDF-1.7".to_vec()),
+            ),
+            (
+                "/page.xhtml",
+                MockResponse::new(200)
+                    .header("Content-Type", "Application/XHTML+XML; charset=utf-8")
+                    .body(OG_COMPLIANT_HTML),
+            ),
+        ]);
+        let result = fetch_with_options(&server.url("/report.pdf"), &FetchOptions::default()).await;
+
+        assert!(matches!(
+            result,
+            Err(Error::UnsupportedContentType(_, content_type)) if content_type == "application/pdf"
+        ));
+        assert!(
+            fetch_with_options(&server.url("/page.xhtml"), &FetchOptions::default())
+                .await
+                .is_ok()
+        );
+
+        let options = FetchOptions {
+            allow_content_types: vec!["application/pdf".to_string()],
+            ..Default::default()
+        };
+
+        assert!(fetch_with_options(&server.url("/report.pdf"), &options)
+            .await
+            .is_ok());
+        assert!(matches!(
+            fetch_with_options(&server.url("/page.xhtml"), &options).await,
+            Err(Error::UnsupportedContentType(_, _))
+        ));
+    }
+
+    #[tokio::test]
+    async fn sends_user_agent() {
+        let server = MockServer::start(vec![("/", MockResponse::html(OG_COMPLIANT_HTML))]);
+
+        fetch_with_options(&server.url("/"), &FetchOptions::default())
+            .await
+            .unwrap();
+        fetch_with_options(
+            &server.url("/"),
+            &FetchOptions::with_user_agent("Mozilla/5.0 (X11; Linux x86_64) Firefox/128.0"),
+        )
+        .await
+        .unwrap();
+
+        let requests: Vec<String> = server
+            .requests()
+            .iter()
+            .map(|request| request.to_lowercase())
+            .collect();
+
+        assert!(requests[0].contains(&format!(
+            "user-agent: mozilla/5.0 (compatible; link-preview/{})\r\n",
+            env!("CARGO_PKG_VERSION")
+        )));
+        assert!(
+            requests[1].contains("user-agent: mozilla/5.0 (x11; linux x86_64) firefox/128.0\r\n")
+        );
+    }
+
+    #[test]
+    fn fetch_futures_are_send() {
+        fn assert_send<T: Send>(_: T) {}
+
+        let options = FetchOptions::default();
+        let url = Url::parse("https://example.com").unwrap();
+
+        assert_send(fetch_detailed("https://example.com"));
+        assert_send(fetch_detailed_with_options("https://example.com", &options));
+        assert_send(fetch_preview("https://example.com"));
+        assert_send(fetch_stream(stream::iter([url.clone()]), 1));
+        assert_send(fetch_stream_with_options(stream::iter([url]), 1, &options));
+    }
+
+    #[tokio::test]
+    async fn falls_back_to_oembed_thumbnail_for_vimeo_videos() {
+        let oembed_server = MockServer::start(vec![(
+            "/oembed",
+            MockResponse::new(200)
+                .header("Content-Type", "application/json")
+                .body(r#"{"type": "video", "provider_name": "Vimeo", "thumbnail_url": "https://i.vimeocdn.com/video/452001751-d_640"}"#),
+        )]);
+        let page = format!(
+            r#"<html><head><meta property="og:site_name" content="Vimeo"><meta property="og:title" content="Coastal Light"><link rel="alternate" type="application/json+oembed" href="{}"></head></html>"#,
+            oembed_server.url("/oembed")
+        );
+        let server = MockServer::start(vec![
+            ("/76979871", MockResponse::html(page)),
+            ("/og-image", MockResponse::html(OG_COMPLIANT_HTML)),
+        ]);
+        let preview = fetch_detailed(&server.url("/76979871"))
+            .await
+            .unwrap()
+            .preview;
+
+        assert_eq!(preview.title.unwrap(), "Coastal Light");
+        assert_eq!(
+            preview.image_url.unwrap().as_str(),
+            "https://i.vimeocdn.com/video/452001751-d_640"
+        );
+
+        fetch_detailed(&server.url("/og-image")).await.unwrap();
+
+        assert_eq!(oembed_server.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn sends_accept_header_tuned_for_html() {
+        let server = MockServer::start(vec![("/", MockResponse::html(OG_COMPLIANT_HTML))]);
+        let options = FetchOptions {
+            accept: "application/xhtml+xml".to_string(),
+            ..Default::default()
+        };
+
+        fetch(&server.url("/")).await.unwrap();
+        fetch_with_options(&server.url("/"), &options)
+            .await
+            .unwrap();
+
+        let requests: Vec<String> = server
+            .requests()
+            .iter()
+            .map(|request| request.to_lowercase())
+            .collect();
+
+        assert!(requests[0].contains("accept: text/html,application/xhtml+xml\r\n"));
+        assert!(requests[1].contains("accept: application/xhtml+xml\r\n"));
+    }
+
+    #[tokio::test]
+    async fn resolves_preview_against_final_url() {
+        let server = MockServer::start(vec![
+            ("/s/abc", MockResponse::redirect("/go/abc")),
+            ("/go/abc", MockResponse::redirect("/posts/static-site")),
+            ("/posts/static-site", MockResponse::html(RELATIVE_URLS_HTML)),
+        ]);
+        let options = FetchOptions {
+            max_redirects: 2,
+            ..Default::default()
+        };
+        let page = fetch_page_with_options(&server.url("/s/abc"), &options)
+            .await
+            .unwrap();
+
+        assert_eq!(page.final_url.as_str(), server.url("/posts/static-site"));
+        assert_eq!(
+            page.preview().image_url.unwrap().as_str(),
+            server.url("/images/covers/static-site.png")
+        );
+    }
+
+    #[tokio::test]
+    async fn fails_when_redirects_exceed_max_redirects() {
+        let server = MockServer::start(vec![
+            ("/loop", MockResponse::redirect("/again")),
+            ("/again", MockResponse::redirect("/loop")),
+        ]);
+        let options = FetchOptions {
+            max_redirects: 3,
+            ..Default::default()
+        };
+        let budgeted = FetchOptions {
+            total_byte_budget: Some(64 * 1024),
+            ..options.clone()
+        };
+
+        for options in [options, budgeted] {
+            let result = fetch_page_with_options(&server.url("/loop"), &options).await;
+
+            assert!(matches!(result, Err(Error::TooManyRedirects(_))));
+        }
+    }
+
+    #[tokio::test]
+    async fn spaces_requests_to_the_same_host() {
+        let server = MockServer::start(vec![
+            ("/a", MockResponse::html(OG_COMPLIANT_HTML)),
+            ("/b", MockResponse::html(OG_COMPLIANT_HTML)),
+            ("/c", MockResponse::html(OG_COMPLIANT_HTML)),
+        ]);
+        let urls = [server.url("/a"), server.url("/b"), server.url("/c")];
+        let urls: Vec<&str> = urls.iter().map(|url| url.as_str()).collect();
+        let options = FetchOptions {
+            per_host_delay: Duration::from_millis(150),
+            ..Default::default()
+        };
+        let started = Instant::now();
+        let results = fetch_many(&urls, 3, &options).await;
+
+        assert!(started.elapsed() >= Duration::from_millis(300));
+        assert_eq!(results.len(), 3);
+        assert!(results.iter().all(|result| result.is_ok()));
+
+        let paths: Vec<String> = server
+            .requests()
+            .iter()
+            .filter_map(|request| {
+                request
+                    .split_whitespace()
+                    .nth(1)
+                    .map(|path| path.to_string())
+            })
+            .collect();
+
+        assert_eq!(paths, vec!["/a", "/b", "/c"]);
+    }
+
+    #[tokio::test]
+    async fn does_not_delay_other_hosts() {
+        let slow_host = MockServer::start(vec![
+            ("/a", MockResponse::html(OG_COMPLIANT_HTML)),
+            ("/b", MockResponse::html(OG_COMPLIANT_HTML)),
+            ("/c", MockResponse::html(OG_COMPLIANT_HTML)),
+        ]);
+        let other_host = MockServer::start(vec![("/", MockResponse::html(OG_COMPLIANT_HTML))]);
+        // Same server, reached through a different host name
+        let other_url = other_host.url("/").replace("127.0.0.1", "localhost");
+        let urls = [
+            slow_host.url("/a"),
+            slow_host.url("/b"),
+            slow_host.url("/c"),
+            other_url,
+        ];
+        let urls: Vec<&str> = urls.iter().map(|url| url.as_str()).collect();
+        let options = FetchOptions {
+            per_host_delay: Duration::from_millis(500),
+            ..Default::default()
+        };
+        let requested_early = async {
+            tokio::time::sleep(Duration::from_millis(250)).await;
+            other_host.requests().len()
+        };
+        let (results, requested_early) =
+            tokio::join!(fetch_many(&urls, 2, &options), requested_early);
+
+        assert_eq!(requested_early, 1);
+        assert!(results.iter().all(|result| result.is_ok()));
+    }
+
+    #[tokio::test]
+    async fn streams_previews_for_every_url() {
+        let server = MockServer::start(vec![
+            ("/a", MockResponse::html(OG_COMPLIANT_HTML)),
+            ("/b", MockResponse::html(YOUTUBE_VIDEO_HTML)),
+            ("/c", MockResponse::html(OG_COMPLIANT_HTML)),
+        ]);
+        let urls: Vec<Url> = ["/a", "/b", "/c"]
+            .iter()
+            .map(|path| Url::parse(&server.url(path)).unwrap())
+            .collect();
+        let results: Vec<(Url, Result<LinkPreview, Error>)> =
+            fetch_stream(stream::iter(urls.clone()), 2).collect().await;
+        let mut fetched: Vec<Url> = results.iter().map(|(url, _)| url.clone()).collect();
+
+        fetched.sort();
+
+        assert_eq!(fetched, urls);
+        assert!(results.iter().all(|(_, result)| result.is_ok()));
+    }
+
+    #[tokio::test]
+    async fn streams_previews_using_options() {
+        let server = MockServer::start(vec![
+            ("/a", MockResponse::html(OG_COMPLIANT_HTML)),
+            ("/b", MockResponse::html(OG_COMPLIANT_HTML)),
+        ]);
+        let urls: Vec<Url> = ["/a", "/b"]
+            .iter()
+            .map(|path| Url::parse(&server.url(path)).unwrap())
+            .collect();
+        let options = FetchOptions::default();
+        let results: Vec<(Url, Result<LinkPreview, Error>)> =
+            fetch_stream_with_options(stream::iter(urls), 2, &options)
+                .collect()
+                .await;
+
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(|(_, result)| result.is_ok()));
+
+        let blocking = FetchOptions {
+            block_private_hosts: true,
+            ..Default::default()
+        };
+        let url = Url::parse(&server.url("/a")).unwrap();
+        let results: Vec<(Url, Result<LinkPreview, Error>)> =
+            fetch_stream_with_options(stream::iter([url]), 2, &blocking)
+                .collect()
+                .await;
+
+        assert!(matches!(results[0].1, Err(Error::BlockedHost(_))));
+    }
+
+    #[tokio::test]
+    async fn times_out_on_slow_hosts() {
+        let server = MockServer::start(vec![(
+            "/",
+            MockResponse::html(OG_COMPLIANT_HTML).delay(Duration::from_millis(500)),
+        )]);
+        let options = FetchOptions {
+            timeout: Duration::from_millis(100),
+            ..Default::default()
+        };
+        let result = fetch_with_options(&server.url("/"), &options).await;
+
+        assert!(matches!(result, Err(Error::Timeout(_))));
+        assert_eq!(FetchOptions::default().timeout, Duration::from_secs(10));
+    }
+
+    #[tokio::test]
+    async fn connect_timeout_leaves_slow_bodies_alone() {
+        let server = MockServer::start(vec![(
+            "/",
+            MockResponse::html(OG_COMPLIANT_HTML).delay(Duration::from_millis(300)),
+        )]);
+        let options = FetchOptions {
+            timeout: Duration::from_secs(5),
+            connect_timeout: Some(Duration::from_millis(100)),
+            ..Default::default()
+        };
+
+        assert!(fetch_with_options(&server.url("/"), &options).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn fails_fast_when_connecting_takes_too_long() {
+        let socket = TcpSocket::new_v4().unwrap();
+
+        socket.bind(SocketAddr::from(([127, 0, 0, 1], 0))).unwrap();
+
+        let listener = socket.listen(1).unwrap();
+        let address = listener.local_addr().unwrap();
+        // The listener never accepts, so once its backlog is full further
+        // connection attempts are left unanswered
+        let _backlog: Vec<TcpStream> = (0..32)
+            .map_while(|_| TcpStream::connect_timeout(&address, Duration::from_millis(100)).ok())
+            .collect();
+        let options = FetchOptions {
+            timeout: Duration::from_secs(30),
+            connect_timeout: Some(Duration::from_millis(100)),
+            ..Default::default()
+        };
+        let started = Instant::now();
+        let result = fetch_with_options(&format!("http://{address}/"), &options).await;
+
+        assert!(matches!(result, Err(Error::Timeout(_))));
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert!(FetchOptions::default().connect_timeout.is_none());
+    }
+
+    #[tokio::test]
+    async fn times_out_reading_slow_responses_despite_connect_timeout() {
+        let server = MockServer::start(vec![(
+            "/",
+            MockResponse::html(OG_COMPLIANT_HTML).delay(Duration::from_secs(2)),
+        )]);
+        let options = FetchOptions {
+            timeout: Duration::from_millis(200),
+            connect_timeout: Some(Duration::from_secs(5)),
+            ..Default::default()
+        };
+        let started = Instant::now();
+        let result = fetch_with_options(&server.url("/"), &options).await;
+
+        assert!(matches!(result, Err(Error::Timeout(_))));
+        assert!(started.elapsed() < Duration::from_secs(2));
+    }
+
+    #[tokio::test]
+    async fn blocks_loopback_hosts() {
+        let server = MockServer::start(vec![("/", MockResponse::html(OG_COMPLIANT_HTML))]);
+        let options = FetchOptions {
+            block_private_hosts: true,
+            ..Default::default()
+        };
+        let result = fetch_with_options(&server.url("/"), &options).await;
+
+        assert!(matches!(result, Err(Error::BlockedHost(_))));
+        assert!(server.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn blocks_link_local_hosts() {
+        let options = FetchOptions {
+            block_private_hosts: true,
+            ..Default::default()
+        };
+        let result = fetch_with_options("http://169.254.169.254/latest/meta-data/", &options).await;
+
+        assert!(matches!(result, Err(Error::BlockedHost(_))));
+    }
+
+    #[tokio::test]
+    async fn blocks_redirects_to_private_hosts_from_allowed_hosts() {
+        let server = MockServer::start(vec![(
+            "/",
+            MockResponse::redirect("http://169.254.169.254/latest/meta-data/"),
+        )]);
+        let options = FetchOptions {
+            block_private_hosts: true,
+            allowed_hosts: Some(vec!["127.0.0.1".to_string()]),
+            ..Default::default()
+        };
+        let result = fetch_with_options(&server.url("/"), &options).await;
+
+        assert!(
+            matches!(result, Err(Error::BlockedHost(url)) if url.starts_with("http://169.254.169.254"))
+        );
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn fetches_allowed_hosts_only() {
+        let server = MockServer::start(vec![("/", MockResponse::html(OG_COMPLIANT_HTML))]);
+        let allowed = FetchOptions {
+            block_private_hosts: true,
+            allowed_hosts: Some(vec!["127.0.0.1".to_string()]),
+            ..Default::default()
+        };
+        let html = fetch_with_options(&server.url("/"), &allowed)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            LinkPreview::from(&html).title.unwrap(),
+            "SEO Strategies for a better web"
+        );
+
+        let restricted = FetchOptions {
+            allowed_hosts: Some(vec!["example.com".to_string()]),
+            ..Default::default()
+        };
+        let result = fetch_with_options(&server.url("/"), &restricted).await;
+
+        assert!(matches!(result, Err(Error::BlockedHost(_))));
+    }
+
+    #[tokio::test]
+    async fn blocks_domains_resolving_to_loopback_addresses() {
+        let server = MockServer::start(vec![("/", MockResponse::html(OG_COMPLIANT_HTML))]);
+        let options = FetchOptions {
+            block_private_hosts: true,
+            ..Default::default()
+        };
+        let url = server.url("/").replace("127.0.0.1", "localhost");
+        let result = fetch_with_options(&url, &options).await;
+
+        assert!(matches!(result, Err(Error::BlockedHost(_))));
+        assert!(server.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn blocks_redirects_to_domains_resolving_to_loopback_addresses() {
+        let target = MockServer::start(vec![("/", MockResponse::html(OG_COMPLIANT_HTML))]);
+        let server = MockServer::start(vec![(
+            "/",
+            MockResponse::redirect(&target.url("/").replace("127.0.0.1", "localhost")),
+        )]);
+        let options = FetchOptions {
+            block_private_hosts: true,
+            allowed_hosts: Some(vec!["127.0.0.1".to_string()]),
+            ..Default::default()
+        };
+        let result = fetch_with_options(&server.url("/"), &options).await;
+
+        assert!(matches!(result, Err(Error::BlockedHost(url)) if url.contains("localhost")));
+        assert!(target.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn fails_when_domains_do_not_resolve_while_blocking_private_hosts() {
+        let options = FetchOptions {
+            block_private_hosts: true,
+            ..Default::default()
+        };
+        let result = fetch_with_options("http://link-preview.invalid/", &options).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn fetches_hosts_allowed_by_filter_only() {
+        let server = MockServer::start(vec![
+            ("/", MockResponse::html(OG_COMPLIANT_HTML)),
+            (
+                "/away",
+                MockResponse::redirect("http://blocked.example.com/"),
+            ),
+        ]);
+        let options = FetchOptions {
+            allowed_host_filter: Some(HostFilter::new(|host| host == "127.0.0.1")),
+            ..Default::default()
+        };
+        let html = fetch_with_options(&server.url("/"), &options)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            LinkPreview::from(&html).title.unwrap(),
+            "SEO Strategies for a better web"
+        );
+
+        let result = fetch_with_options(&server.url("/away"), &options).await;
+
+        assert!(
+            matches!(result, Err(Error::BlockedHost(url)) if url == "http://blocked.example.com/")
+        );
+
+        let url = server.url("/").replace("127.0.0.1", "localhost");
+        let result = fetch_with_options(&url, &options).await;
+
+        assert!(matches!(result, Err(Error::BlockedHost(_))));
+    }
+
+    #[tokio::test]
+    async fn fetches_public_hosts_when_blocking_private_hosts() {
+        let options = FetchOptions {
+            block_private_hosts: true,
+            ..Default::default()
+        };
+        let html = fetch_with_options(REMOTE_FULL_FEATURED_HTML, &options)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            LinkPreview::from(&html).title.unwrap(),
+            "SEO Strategies for a better web"
+        );
+    }
+
+    #[cfg(feature = "encoding")]
+    #[tokio::test]
+    async fn decodes_pages_in_the_charset_of_the_response() {
+        let server = MockServer::start(vec![(
+            "/",
+            MockResponse::new(200)
+                .header("Content-Type", "text/html; charset=\"windows-1252\"")
+                .body(&b"<html><head><title>Caf\xe9 M\xfcller</title></head></html>"[..]),
+        )]);
+        let html = fetch_with_options(&server.url("/"), &FetchOptions::default())
+            .await
+            .unwrap();
+
+        assert_eq!(LinkPreview::from(&html).title.unwrap(), "Café Müller");
+
+        let preview = fetch_detailed(&server.url("/")).await.unwrap().preview;
+
+        assert_eq!(preview.title.unwrap(), "Café Müller");
+    }
+
+    #[tokio::test]
+    async fn replaces_malformed_utf8_in_pages_declaring_no_encoding() {
+        let server = MockServer::start(vec![(
+            "/",
+            MockResponse::new(200)
+                .header("Content-Type", "text/html")
+                .body(&b"<html><head><title>Caf\xe9</title></head></html>"[..]),
+        )]);
+        let html = fetch(&server.url("/")).await.unwrap();
+        let preview = fetch_preview(&server.url("/")).await.unwrap();
+
+        assert_eq!(LinkPreview::from(&html).title.unwrap(), "Caf\u{fffd}");
+        assert_eq!(preview.title.unwrap(), "Caf\u{fffd}");
+    }
+
+    #[cfg(feature = "encoding")]
+    #[tokio::test]
+    async fn decodes_pages_in_the_charset_they_declare() {
+        let server = MockServer::start(vec![(
+            "/",
+            MockResponse::new(200)
+                .header("Content-Type", "text/html")
+                .body(crate::tests::WINDOWS_1252_HTML),
+        )]);
+        let html = fetch_with_options(&server.url("/"), &FetchOptions::default())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            LinkPreview::from(&html).title.unwrap(),
+            "Café Müller – Crêpes & Gâteaux"
+        );
+    }
+
+    #[test]
+    fn creates_preview_from_bytes_resolving_relative_urls() {
+        let url = Url::parse("https://blog.example.com/posts/static-site").unwrap();
+        let preview = preview_from_bytes(RELATIVE_URLS_HTML, &url).unwrap();
+
+        assert_eq!(
+            preview.title.unwrap(),
+            "Notes on building a tiny static site generator"
+        );
+        assert_eq!(
+            preview.image_url.unwrap().as_str(),
+            "https://blog.example.com/images/covers/static-site.png"
+        );
+        assert_eq!(preview.domain.unwrap(), "blog.example.com");
+    }
+
+    #[test]
+    fn resolves_relative_urls_of_profile_extracted_previews() {
+        let url = Url::parse("https://crumbs.example.com/2024/05/flat-loaf/").unwrap();
+        let html = r#"<html><head>
+            <link rel="https://api.w.org/" href="https://crumbs.example.com/wp-json/">
+            <link rel="icon" href="/favicon.png">
+            <meta property="og:title" content="Why did my loaf come out flat?">
+            <meta property="og:image" content="/wp-content/uploads/flat-loaf.jpg">
+        </head></html>"#;
+        let preview = preview_from_bytes(html.as_bytes(), &url).unwrap();
+
+        assert_eq!(
+            preview.image_url.unwrap().as_str(),
+            "https://crumbs.example.com/wp-content/uploads/flat-loaf.jpg"
+        );
+        assert_eq!(
+            preview.favicon.unwrap().as_str(),
+            "https://crumbs.example.com/favicon.png"
+        );
+    }
+
+    #[test]
+    fn creates_preview_from_bytes_using_fitting_profile() {
+        let url = Url::parse("https://youtu.be/61JHONRXhjs").unwrap();
+        let preview = preview_from_bytes(YOUTUBE_VIDEO_HTML, &url).unwrap();
+
+        assert_eq!(
+            preview.image_url.unwrap().as_str(),
+            "https://i.ytimg.com/vi/61JHONRXhjs/maxresdefault.jpg"
+        );
+    }
 }
@@ -1,11 +1,57 @@
+use std::sync::LazyLock;
+
 use regex::Regex;
-use scraper::{Html, Selector};
+use scraper::node::Element;
+use scraper::{ElementRef, Html, Selector};
+use url::Url;
+
+use crate::config::ExtractionConfig;
+use crate::urls::resolve_url;
+
+/// Named character references decoded by `decode_html_entities`
+const NAMED_ENTITIES: [(&str, char); 30] = [
+    ("amp", '&'),
+    ("lt", '<'),
+    ("gt", '>'),
+    ("quot", '"'),
+    ("apos", '\''),
+    ("nbsp", '\u{a0}'),
+    ("copy", '©'),
+    ("reg", '®'),
+    ("trade", '™'),
+    ("hellip", '…'),
+    ("mdash", '—'),
+    ("ndash", '–'),
+    ("lsquo", '‘'),
+    ("rsquo", '’'),
+    ("ldquo", '“'),
+    ("rdquo", '”'),
+    ("laquo", '«'),
+    ("raquo", '»'),
+    ("middot", '·'),
+    ("bull", '•'),
+    ("euro", '€'),
+    ("aacute", 'á'),
+    ("eacute", 'é'),
+    ("iacute", 'í'),
+    ("oacute", 'ó'),
+    ("uacute", 'ú'),
+    ("egrave", 'è'),
+    ("ntilde", 'ñ'),
+    ("ccedil", 'ç'),
+    ("uuml", 'ü'),
+];
 
+/// Selectors for the elements holding a document's main content, by priority
+const MAIN_CONTENT_SELECTORS: [&str; 3] = ["article", "main", "[role=\"main\"]"];
+
+/// Retrieves the inner HTML of the first `tag` element, with its whitespace
+/// normalized by `normalize_whitespace`
 pub fn first_inner_html(html: &Html, tag: &str) -> Option<String> {
     let selector = Selector::parse(tag).unwrap();
 
     if let Some(element) = html.select(&selector).next() {
-        let value = element.inner_html();
+        let value = normalize_whitespace(&element.inner_html());
 
         if !value.is_empty() {
             return Some(value);
@@ -15,22 +61,196 @@ pub fn first_inner_html(html: &Html, tag: &str) -> Option<String> {
     None
 }
 
+/// Retrieves the value of the provided `<meta>` element: its `content`
+/// attribute, or its `value` attribute on malformed pages declaring it
+/// instead
+pub fn meta_content(element: &Element) -> Option<&str> {
+    element.attr("content").or_else(|| element.attr("value"))
+}
+
+/// Retrieves the `content` of the first `<meta>` element with the provided
+/// `property` attribute, same as `find_meta_by_property`
 pub fn find_meta_tag(html: &Html, property: &str) -> Option<String> {
-    let selector = Selector::parse(&format!("meta[property=\"{property}\"]")).unwrap();
+    find_meta_by_property(html, property)
+}
 
-    if let Some(element) = html.select(&selector).next() {
-        if let Some(value) = element.value().attr("content") {
-            return Some(value.to_string());
+/// Retrieves the first `<meta>` element whose `attr` attribute is exactly
+/// `value`.
+///
+/// Attributes are compared directly rather than through a selector built from
+/// `value`, as it may hold any character, including quotes.
+pub fn find_meta_element<'a>(html: &'a Html, attr: &str, value: &str) -> Option<ElementRef<'a>> {
+    static SELECTOR: LazyLock<Selector> = LazyLock::new(|| Selector::parse("meta").unwrap());
+
+    html.select(&SELECTOR)
+        .find(|element| element.value().attr(attr) == Some(value))
+}
+
+/// Retrieves the `content` of the first `<meta>` element with the provided
+/// `property` attribute, e.g. `og:title`
+pub fn find_meta_by_property(html: &Html, property: &str) -> Option<String> {
+    if let Some(element) = find_meta_element(html, "property", property) {
+        if let Some(value) = meta_content(element.value()) {
+            return Some(value.trim().to_string());
         }
     }
 
     None
 }
 
+/// Locates the element holding the document's main content: the first
+/// `<article>`, `<main>` or `[role="main"]` element, in that order of
+/// preference
+pub fn find_main_content(html: &Html) -> Option<ElementRef<'_>> {
+    static SELECTORS: LazyLock<[Selector; 3]> =
+        LazyLock::new(|| MAIN_CONTENT_SELECTORS.map(|selector| Selector::parse(selector).unwrap()));
+
+    SELECTORS
+        .iter()
+        .find_map(|selector| html.select(selector).next())
+}
+
+/// Same as `first_inner_html` but scoped to the document's main content when
+/// it can be located and holds a `tag` element
+pub fn first_content_inner_html(html: &Html, tag: &str) -> Option<String> {
+    let selector = Selector::parse(tag).unwrap();
+
+    if let Some(element) =
+        find_main_content(html).and_then(|content| content.select(&selector).next())
+    {
+        let value = normalize_whitespace(&element.inner_html());
+
+        if !value.is_empty() {
+            return Some(value);
+        }
+    }
+
+    first_inner_html(html, tag)
+}
+
+/// Retrieves the text of the document's main heading, preferring the first
+/// `<h1>` inside the main content (see `find_main_content`) over one
+/// elsewhere, such as in a sidebar or wrapping the site's logo.
+///
+/// Headings without any text are skipped and the whitespace of the text is
+/// normalized.
+pub fn find_main_heading(html: &Html) -> Option<String> {
+    static SELECTOR: LazyLock<Selector> = LazyLock::new(|| Selector::parse("h1").unwrap());
+
+    find_main_content(html)
+        .and_then(|content| first_text(content.select(&SELECTOR)))
+        .or_else(|| first_text(html.select(&SELECTOR)))
+}
+
+/// Retrieves the text, with its whitespace normalized, of the first of the
+/// provided elements holding any
+fn first_text<'a>(mut elements: impl Iterator<Item = ElementRef<'a>>) -> Option<String> {
+    elements.find_map(|element| {
+        let text = normalize_whitespace(&element.text().collect::<String>());
+
+        (!text.is_empty()).then_some(text)
+    })
+}
+
+/// Retrieves the `content` of the first `<meta>` element with the provided
+/// `name` attribute, e.g. `format-detection`
+pub fn find_meta_by_name(html: &Html, name: &str) -> Option<String> {
+    if let Some(element) = find_meta_element(html, "name", name) {
+        if let Some(value) = meta_content(element.value()) {
+            return Some(value.trim().to_string());
+        }
+    }
+
+    None
+}
+
+/// Retrieves the `content` of the first `<meta>` element with the provided
+/// `http-equiv` attribute, compared case-insensitively, e.g. `last-modified`
+pub fn find_meta_by_http_equiv(html: &Html, name: &str) -> Option<String> {
+    static SELECTOR: LazyLock<Selector> =
+        LazyLock::new(|| Selector::parse("meta[http-equiv]").unwrap());
+
+    html.select(&SELECTOR)
+        .find(|element| {
+            element
+                .value()
+                .attr("http-equiv")
+                .is_some_and(|value| value.eq_ignore_ascii_case(name))
+        })
+        .and_then(|element| meta_content(element.value()))
+        .map(|value| value.to_string())
+}
+
+/// Retrieves the text of the first `<figcaption>` element holding any, with
+/// its whitespace collapsed
+pub fn first_figcaption_text(html: &Html) -> Option<String> {
+    static SELECTOR: LazyLock<Selector> = LazyLock::new(|| Selector::parse("figcaption").unwrap());
+
+    html.select(&SELECTOR).find_map(|element| {
+        let text = normalize_whitespace(&element.text().collect::<String>());
+
+        (!text.is_empty()).then_some(text)
+    })
+}
+
+/// Retrieves the key and `content` of every `<meta>` element in document
+/// order. The key is taken from the `property` attribute, falling back to
+/// `name`, and elements without either are skipped.
+///
+/// When `lowercase_keys` is `true` keys are lowercased (e.g. `OG:Title`
+/// becomes `og:title`), otherwise they are kept as declared. Values are
+/// always kept as declared.
+pub fn extract_all_meta(html: &Html, lowercase_keys: bool) -> Vec<(String, String)> {
+    static SELECTOR: LazyLock<Selector> =
+        LazyLock::new(|| Selector::parse("meta[content]").unwrap());
+
+    html.select(&SELECTOR)
+        .filter_map(|element| {
+            let element = element.value();
+            let key = element.attr("property").or_else(|| element.attr("name"))?;
+            let value = element.attr("content")?;
+            let key = if lowercase_keys {
+                key.to_lowercase()
+            } else {
+                key.to_string()
+            };
+
+            Some((key, value.to_string()))
+        })
+        .collect()
+}
+
+/// Retrieves the short name of the web application the document belongs to,
+/// declared through `<meta name="application-name">`
+pub fn find_application_name(html: &Html) -> Option<String> {
+    find_meta_by_name(html, "application-name")
+}
+
+/// Retrieves the referrer policy declared by the document through the
+/// `<meta name="referrer">` element, e.g. `no-referrer` or `origin`
+pub fn find_referrer_policy(html: &Html) -> Option<String> {
+    find_meta_by_name(html, "referrer")
+}
+
+/// Retrieves the color schemes the document can be rendered in, declared
+/// through the `<meta name="color-scheme">` element, e.g. `light dark`,
+/// `dark` or `only light`
+pub fn find_color_scheme(html: &Html) -> Option<String> {
+    find_meta_by_name(html, "color-scheme")
+}
+
+/// Retrieves the `href` of the first `<link>` element with the provided `rel`.
+///
+/// The whole document is scanned, so `<link>` elements mistakenly placed in
+/// the `<body>` are found too. When many elements match, the first occurrence
+/// in document order wins.
 pub fn find_link(html: &Html, rel: &str) -> Option<String> {
-    let selector = Selector::parse(&format!("link[rel=\"{rel}\"]")).unwrap();
+    static SELECTOR: LazyLock<Selector> = LazyLock::new(|| Selector::parse("link[rel]").unwrap());
 
-    if let Some(element) = html.select(&selector).next() {
+    if let Some(element) = html
+        .select(&SELECTOR)
+        .find(|element| element.value().attr("rel") == Some(rel))
+    {
         if let Some(value) = element.value().attr("href") {
             return Some(value.to_string());
         }
@@ -39,6 +259,401 @@ pub fn find_link(html: &Html, rel: &str) -> Option<String> {
     None
 }
 
+/// Retrieves the `href` of the first `<base>` element declaring one, parsed,
+/// along with the `target` of the first `<base>` element declaring one, e.g.
+/// `_blank`.
+///
+/// The `href` is only provided when it's an absolute URL, use
+/// `document_base_url` to resolve a relative one. When the document has no
+/// `<base>` element declaring either, `None` is returned.
+pub fn find_base(html: &Html) -> Option<(Option<Url>, Option<String>)> {
+    let href = find_base_attr(html, "href").map(|href| Url::parse(&href));
+    let target = find_base_attr(html, "target");
+
+    if href.is_none() && target.is_none() {
+        return None;
+    }
+
+    Some((href.and_then(Result::ok), target))
+}
+
+/// Retrieves the URL relative URLs in the document are resolved against: the
+/// `<base href>`, itself resolved against `document_url` when relative, or
+/// `document_url` when there's none
+pub fn document_base_url(html: &Html, document_url: Option<&Url>) -> Option<Url> {
+    find_base_attr(html, "href")
+        .and_then(|href| resolve_url(&href, document_url))
+        .or_else(|| document_url.cloned())
+}
+
+/// Retrieves the trimmed and non-empty `attr` of the first `<base>` element
+/// declaring it
+fn find_base_attr(html: &Html, attr: &str) -> Option<String> {
+    static SELECTOR: LazyLock<Selector> = LazyLock::new(|| Selector::parse("base").unwrap());
+
+    html.select(&SELECTOR)
+        .find_map(|element| element.value().attr(attr))
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+/// Image candidate declared in a `srcset` or `imagesrcset` attribute
+#[derive(Clone, Debug, PartialEq)]
+pub struct SrcsetCandidate {
+    pub url: String,
+    /// Width descriptor, e.g. `800` for `800w`
+    pub width: Option<u32>,
+    /// Pixel density descriptor, e.g. `2.0` for `2x`
+    pub density: Option<f32>,
+}
+
+/// Parses the image candidates of a `srcset` or `imagesrcset` attribute, e.g.
+/// `hero-800.jpg 800w, hero-1600.jpg 1600w`, in declaration order.
+///
+/// URLs may hold commas (e.g. `w_800,h_600`) as long as they aren't trailing.
+/// Candidates with a descriptor which isn't a width (`w`) or a pixel density
+/// (`x`) are skipped.
+pub fn parse_srcset(value: &str) -> Vec<SrcsetCandidate> {
+    let mut candidates: Vec<SrcsetCandidate> = Vec::new();
+    let mut rest = value;
+
+    loop {
+        rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+
+        if rest.is_empty() {
+            break;
+        }
+
+        let (url, after) = rest.split_at(rest.find(char::is_whitespace).unwrap_or(rest.len()));
+        let descriptor = if url.ends_with(',') {
+            rest = after;
+            ""
+        } else {
+            let end = after.find(',').unwrap_or(after.len());
+            rest = &after[end..];
+            after[..end].trim()
+        };
+        let url = url.trim_end_matches(',');
+        let (width, density) = if descriptor.is_empty() {
+            (None, None)
+        } else if let Some(width) = descriptor.strip_suffix('w') {
+            let Ok(width) = width.parse::<u32>() else {
+                continue;
+            };
+
+            (Some(width), None)
+        } else if let Some(density) = descriptor.strip_suffix('x') {
+            let Ok(density) = density.parse::<f32>() else {
+                continue;
+            };
+
+            (None, Some(density))
+        } else {
+            continue;
+        };
+
+        candidates.push(SrcsetCandidate {
+            url: url.to_string(),
+            width,
+            density,
+        });
+    }
+
+    candidates
+}
+
+/// Picks the largest of the provided candidates: the widest one, or the one
+/// with the highest pixel density when widths aren't declared. Candidates
+/// without any descriptor count as `1x`.
+pub fn best_srcset_candidate(candidates: &[SrcsetCandidate]) -> Option<&SrcsetCandidate> {
+    candidates.iter().reduce(|best, candidate| {
+        let larger = candidate.width.cmp(&best.width).then(
+            candidate
+                .density
+                .unwrap_or(1.0)
+                .total_cmp(&best.density.unwrap_or(1.0)),
+        );
+
+        if larger.is_gt() {
+            candidate
+        } else {
+            best
+        }
+    })
+}
+
+/// Retrieves the image preloaded by the first `<link rel="preload"
+/// as="image">` element, which pages use for their hero image. The best
+/// candidate of its `imagesrcset` is used, falling back to its `href`.
+///
+/// `imagesizes` is not taken into account, as the largest candidate is the
+/// most suitable for a preview regardless of the viewport.
+pub fn find_preload_image(html: &Html) -> Option<String> {
+    static SELECTOR: LazyLock<Selector> =
+        LazyLock::new(|| Selector::parse("link[rel~=\"preload\"][as=\"image\"]").unwrap());
+    let element = html.select(&SELECTOR).next()?.value();
+    let candidates = element
+        .attr("imagesrcset")
+        .map(parse_srcset)
+        .unwrap_or_default();
+
+    best_srcset_candidate(&candidates)
+        .map(|candidate| candidate.url.to_string())
+        .or_else(|| element.attr("href").map(|href| href.trim().to_string()))
+        .filter(|src| !src.is_empty())
+}
+
+/// Retrieves the `href` of the first `<link>` element matching any of the
+/// provided `rels`, in their order of preference.
+///
+/// Unlike `find_link`, `rel` values are compared case-insensitively and
+/// ignoring extra whitespace, so `rel="Shortcut  Icon"` matches
+/// `shortcut icon`. Elements with any other `rel` are skipped.
+pub fn find_first_link(html: &Html, rels: &[&str]) -> Option<String> {
+    static SELECTOR: LazyLock<Selector> =
+        LazyLock::new(|| Selector::parse("link[rel][href]").unwrap());
+    let links: Vec<(String, &str)> = html
+        .select(&SELECTOR)
+        .filter_map(|element| {
+            let element = element.value();
+            let rel = element
+                .attr("rel")?
+                .split_ascii_whitespace()
+                .collect::<Vec<&str>>()
+                .join(" ");
+
+            Some((rel, element.attr("href")?))
+        })
+        .collect();
+
+    rels.iter().find_map(|rel| {
+        links
+            .iter()
+            .find(|(value, _)| value.eq_ignore_ascii_case(rel))
+            .map(|(_, href)| href.to_string())
+    })
+}
+
+/// Valid values for the `dir` attribute
+const TEXT_DIRECTIONS: [&str; 3] = ["ltr", "rtl", "auto"];
+
+/// Retrieves the text direction declared through the `dir` attribute on the
+/// `<html>` element, or on `<body>` when `<html>` doesn't declare one.
+///
+/// The value is lowercased and must be one of `ltr`, `rtl` or `auto`, invalid
+/// values are ignored.
+pub fn find_text_direction(html: &Html) -> Option<String> {
+    static SELECTORS: LazyLock<[Selector; 2]> = LazyLock::new(|| {
+        ["html[dir]", "body[dir]"].map(|selector| Selector::parse(selector).unwrap())
+    });
+
+    SELECTORS.iter().find_map(|selector| {
+        let dir = html.select(selector).next()?.value().attr("dir")?;
+        let dir = dir.trim().to_lowercase();
+
+        TEXT_DIRECTIONS.contains(&dir.as_str()).then_some(dir)
+    })
+}
+
+/// Retrieves the keywords declared by the document through the comma
+/// separated `<meta name="keywords">` element followed by every OpenGraph
+/// `article:tag` meta tag, in document order.
+///
+/// Keywords are trimmed, blank ones are dropped and duplicates, compared
+/// case-insensitively, are dropped keeping the first occurrence.
+pub fn find_keywords(html: &Html) -> Vec<String> {
+    static SELECTOR: LazyLock<Selector> =
+        LazyLock::new(|| Selector::parse("meta[property=\"article:tag\"]").unwrap());
+    let declared = find_meta_by_name(html, "keywords").unwrap_or_default();
+    let tags = html
+        .select(&SELECTOR)
+        .filter_map(|element| meta_content(element.value()))
+        .map(|keyword| keyword.to_string());
+    let mut keywords: Vec<String> = Vec::new();
+
+    for keyword in declared
+        .split(',')
+        .map(|keyword| keyword.to_string())
+        .chain(tags)
+    {
+        let keyword = keyword.trim();
+
+        if !keyword.is_empty()
+            && !keywords
+                .iter()
+                .any(|existing| existing.to_lowercase() == keyword.to_lowercase())
+        {
+            keywords.push(keyword.to_string());
+        }
+    }
+
+    keywords
+}
+
+/// Retrieves the language of the document, as a language tag, in the
+/// following order:
+///
+/// - The `lang` attribute of the `<html>` element, e.g. `en-US`
+/// - OpenGraph's locale meta tag (`og:locale`), normalised to a language tag,
+///   e.g. `en-US` for `en_US`
+/// - The `<meta http-equiv="content-language">` element, whose first
+///   language is used when it lists many, e.g. `de` for `de, en`
+///
+/// Values are trimmed and otherwise kept as declared, blank ones are skipped.
+pub fn find_language(html: &Html) -> Option<String> {
+    static SELECTOR: LazyLock<Selector> = LazyLock::new(|| Selector::parse("html[lang]").unwrap());
+    let non_blank = |lang: &str| {
+        let lang = lang.trim();
+
+        (!lang.is_empty()).then(|| lang.to_string())
+    };
+
+    html.select(&SELECTOR)
+        .next()
+        .and_then(|element| element.value().attr("lang"))
+        .and_then(non_blank)
+        .or_else(|| {
+            find_meta_tag(html, "og:locale")
+                .as_deref()
+                .and_then(non_blank)
+                .map(|locale| locale.replace('_', "-"))
+        })
+        .or_else(|| {
+            find_meta_by_http_equiv(html, "content-language")
+                .as_deref()
+                .and_then(|languages| languages.split(',').next())
+                .and_then(non_blank)
+        })
+}
+
+/// Hints on an `<img>` element's `class` or `alt` attributes suggesting it's
+/// an icon or logo rather than content
+const NON_CONTENT_IMAGE_HINTS: [&str; 3] = ["logo", "icon", "avatar"];
+
+/// Retrieves the `src` of every `<img>` inside the document's `<article>` or
+/// `<main>` elements in document order, skipping images hinted as icons or
+/// logos and images declared smaller than 100 pixels on either side.
+pub fn content_image_sources(html: &Html) -> Vec<String> {
+    content_image_sources_with_config(html, &ExtractionConfig::default())
+}
+
+/// Same as `content_image_sources`, skipping images declared smaller than
+/// `ExtractionConfig::min_image_size` and additionally retrieving the images
+/// inside `<noscript>` elements when `ExtractionConfig::noscript` is enabled
+pub fn content_image_sources_with_config(html: &Html, config: &ExtractionConfig) -> Vec<String> {
+    static SELECTOR: LazyLock<Selector> =
+        LazyLock::new(|| Selector::parse("article img, main img").unwrap());
+    let mut sources: Vec<String> = html
+        .select(&SELECTOR)
+        .filter(|element| is_content_image(element, config.min_image_size))
+        .filter_map(|element| element.value().attr("src"))
+        .map(|src| src.to_string())
+        .collect();
+
+    if config.noscript {
+        static NOSCRIPT_SELECTOR: LazyLock<Selector> =
+            LazyLock::new(|| Selector::parse("article noscript, main noscript").unwrap());
+        static IMG_SELECTOR: LazyLock<Selector> = LazyLock::new(|| Selector::parse("img").unwrap());
+
+        for element in html.select(&NOSCRIPT_SELECTOR) {
+            // `<noscript>` contents are parsed as text when scripting is
+            // enabled, as `scraper` does
+            let fragment = Html::parse_fragment(&element.text().collect::<String>());
+
+            sources.extend(
+                fragment
+                    .select(&IMG_SELECTOR)
+                    .filter(|element| is_content_image(element, config.min_image_size))
+                    .filter_map(|element| element.value().attr("src"))
+                    .map(|src| src.to_string()),
+            );
+        }
+    }
+
+    sources
+}
+
+/// Checks whether the `<img>` element isn't hinted as an icon or logo and
+/// isn't declared smaller than `min_size` on either side
+fn is_content_image(element: &ElementRef, min_size: u32) -> bool {
+    let element = element.value();
+    let hinted = ["class", "alt"].iter().any(|attr| {
+        element.attr(attr).is_some_and(|value| {
+            let value = value.to_lowercase();
+
+            NON_CONTENT_IMAGE_HINTS
+                .iter()
+                .any(|hint| value.contains(hint))
+        })
+    });
+    let undersized = ["width", "height"].iter().any(|attr| {
+        element
+            .attr(attr)
+            .and_then(|value| value.trim().parse::<u32>().ok())
+            .is_some_and(|size| size < min_size)
+    });
+
+    !hinted && !undersized
+}
+
+/// Trims the provided text and collapses every internal run of whitespace,
+/// including newlines left by indented HTML, into a single space
+pub fn normalize_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+/// Decodes the named (e.g. `&amp;`) and numeric (e.g. `&#39;`, `&#x2014;`)
+/// character references in the provided text. Unknown or malformed references
+/// are kept as-is.
+pub fn decode_html_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest[1..]
+            .find(';')
+            .map(|end| &rest[1..=end])
+            .and_then(|reference| {
+                decode_entity(reference).map(|character| (character, reference.len() + 2))
+            });
+
+        match entity {
+            Some((character, len)) => {
+                decoded.push(character);
+                rest = &rest[len..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+/// Decodes a single character reference, without its leading `&` and
+/// trailing `;`
+fn decode_entity(reference: &str) -> Option<char> {
+    if let Some(code) = reference.strip_prefix('#') {
+        let code = match code.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => code.parse::<u32>().ok()?,
+        };
+
+        return char::from_u32(code);
+    }
+
+    NAMED_ENTITIES
+        .iter()
+        .find(|(name, _)| *name == reference)
+        .map(|(_, character)| *character)
+}
+
 /// Removes HTML tags from the provided HTML text
 pub fn remove_html_tags(text: &str) -> String {
     let re = Regex::new("<(.|\n)*?>").unwrap();
@@ -49,7 +664,47 @@ pub fn remove_html_tags(text: &str) -> String {
 
 #[cfg(test)]
 mod tests {
-    use super::remove_html_tags;
+    use scraper::Html;
+    use url::Url;
+
+    use crate::html_from_bytes;
+    use crate::tests::{
+        APPLICATION_NAME_HTML, ARTICLE_PARAGRAPH_HTML, BASE_ELEMENT_HTML, BODY_CANONICAL_HTML,
+        COLOR_SCHEME_HTML, CONTENT_IMAGE_HTML, CONTENT_LANGUAGE_HTML, FORMAT_DETECTION_HTML,
+        HTML_ENTITIES_HTML, KEYWORDS_HTML, MIXED_CASE_META_HTML, MULTILINE_TEXT_HTML,
+        OG_COMPLIANT_HTML, PRELOAD_IMAGE_HTML, REFERRER_POLICY_HTML, RTL_DIRECTION_HTML,
+        SIDEBAR_HEADING_HTML,
+    };
+
+    use super::{
+        best_srcset_candidate, content_image_sources, decode_html_entities, document_base_url,
+        extract_all_meta, find_application_name, find_base, find_color_scheme, find_keywords,
+        find_language, find_link, find_main_heading, find_meta_by_name, find_meta_by_property,
+        find_preload_image, find_referrer_policy, find_text_direction, first_content_inner_html,
+        first_inner_html, normalize_whitespace, parse_srcset, remove_html_tags, SrcsetCandidate,
+    };
+
+    #[test]
+    fn decodes_html_entities() {
+        assert_eq!(
+            decode_html_entities("Tom &amp; Jerry&#39;s &quot;Caf&eacute;&quot; &#x2014; &copy;"),
+            "Tom & Jerry's \"Café\" — ©"
+        );
+        assert_eq!(
+            decode_html_entities("&unknown; & &#xZZ; &#1114112; &amp"),
+            "&unknown; & &#xZZ; &#1114112; &amp"
+        );
+    }
+
+    #[test]
+    fn decodes_entities_in_meta_content() {
+        let html = html_from_bytes(HTML_ENTITIES_HTML).unwrap();
+
+        assert_eq!(
+            find_meta_by_property(&html, "og:title").unwrap(),
+            "Tom & Jerry's \"Big Day\" — Café"
+        );
+    }
 
     #[test]
     fn sanitizes_html_text() {
@@ -61,4 +716,309 @@ mod tests {
             "Hello World!. This is our1st test on sanitization for HTML text"
         );
     }
+
+    #[test]
+    fn skips_icons_and_logos_in_content_images() {
+        let html = html_from_bytes(CONTENT_IMAGE_HTML).unwrap();
+        let sources = content_image_sources(&html);
+
+        assert_eq!(
+            sources,
+            vec![
+                "/images/relative-map.jpg",
+                "https://example.com/images/sierra-nevada.jpg"
+            ]
+        );
+    }
+
+    #[test]
+    fn finds_first_link_placed_in_body() {
+        let html = html_from_bytes(BODY_CANONICAL_HTML).unwrap();
+        let canonical = find_link(&html, "canonical");
+
+        assert_eq!(
+            canonical.unwrap(),
+            "https://news.example.com/2024/bike-lanes"
+        );
+    }
+
+    #[test]
+    fn finds_application_name() {
+        let html = html_from_bytes(APPLICATION_NAME_HTML).unwrap();
+
+        assert_eq!(find_application_name(&html).unwrap(), "Trellis");
+    }
+
+    #[test]
+    fn prefers_main_content_heading() {
+        let html = html_from_bytes(SIDEBAR_HEADING_HTML).unwrap();
+
+        assert_eq!(
+            find_main_heading(&html).unwrap(),
+            "Analysis: Why the city's bike lanes are finally working"
+        );
+
+        let html = Html::parse_document(
+            r#"<h1><img src="/logo.svg" alt=""></h1><aside><h1>Trending now</h1></aside>"#,
+        );
+
+        assert_eq!(find_main_heading(&html).unwrap(), "Trending now");
+        assert!(find_main_heading(&Html::parse_document("<p>No headings</p>")).is_none());
+    }
+
+    #[test]
+    fn finds_color_scheme() {
+        let html = html_from_bytes(COLOR_SCHEME_HTML).unwrap();
+
+        assert_eq!(find_color_scheme(&html).unwrap(), "light dark");
+        assert!(find_color_scheme(&html_from_bytes(APPLICATION_NAME_HTML).unwrap()).is_none());
+    }
+
+    #[test]
+    fn finds_referrer_policy() {
+        let html = html_from_bytes(REFERRER_POLICY_HTML).unwrap();
+        let policy = find_referrer_policy(&html);
+
+        assert_eq!(policy.unwrap(), "strict-origin-when-cross-origin");
+    }
+
+    #[test]
+    fn scopes_first_inner_html_to_main_content() {
+        let html = html_from_bytes(ARTICLE_PARAGRAPH_HTML).unwrap();
+        let paragraph = first_content_inner_html(&html, "p");
+
+        assert_eq!(
+            paragraph.unwrap(),
+            "A forgiving starter schedule and a single loaf recipe to build confidence."
+        );
+    }
+
+    #[test]
+    fn finds_keywords() {
+        let html = html_from_bytes(KEYWORDS_HTML).unwrap();
+
+        assert_eq!(
+            find_keywords(&html),
+            [
+                "chillies",
+                "gardening",
+                "indoor plants",
+                "Windowsill",
+                "Seed starting",
+                "Peppers"
+            ]
+        );
+        assert!(find_keywords(&html_from_bytes(CONTENT_IMAGE_HTML).unwrap()).is_empty());
+    }
+
+    #[test]
+    fn finds_language() {
+        let html = Html::parse_document(
+            r#"<html lang=" en-US "><head><meta property="og:locale" content="fr_FR"></head></html>"#,
+        );
+
+        assert_eq!(find_language(&html).unwrap(), "en-US");
+        assert_eq!(
+            find_language(&html_from_bytes(OG_COMPLIANT_HTML).unwrap()).unwrap(),
+            "en"
+        );
+        assert_eq!(
+            find_language(&Html::parse_document(
+                r#"<html lang=""><head><meta property="og:locale" content="fr_FR"></head></html>"#
+            ))
+            .unwrap(),
+            "fr-FR"
+        );
+        assert_eq!(
+            find_language(&html_from_bytes(CONTENT_LANGUAGE_HTML).unwrap()).unwrap(),
+            "de-AT"
+        );
+        assert!(find_language(&Html::parse_document("<title>No language</title>")).is_none());
+    }
+
+    #[test]
+    fn finds_text_direction() {
+        let html = html_from_bytes(RTL_DIRECTION_HTML).unwrap();
+
+        assert_eq!(find_text_direction(&html).unwrap(), "rtl");
+    }
+
+    #[test]
+    fn falls_back_to_body_text_direction() {
+        let html =
+            html_from_bytes(b"<html dir=\"sideways\"><body dir=\"auto\"></body></html>").unwrap();
+
+        assert_eq!(find_text_direction(&html).unwrap(), "auto");
+    }
+
+    #[test]
+    fn finds_no_text_direction_when_undeclared() {
+        let html = html_from_bytes(b"<html><body><p>Hello</p></body></html>").unwrap();
+
+        assert!(find_text_direction(&html).is_none());
+    }
+
+    #[test]
+    fn extracts_all_meta_preserving_key_casing() {
+        let html = html_from_bytes(MIXED_CASE_META_HTML).unwrap();
+        let meta = extract_all_meta(&html, false);
+        let keys: Vec<&str> = meta.iter().map(|(key, _)| key.as_str()).collect();
+
+        assert_eq!(
+            keys,
+            vec![
+                "OG:Title",
+                "og:Description",
+                "Twitter:Card",
+                "Description",
+                "og:image"
+            ]
+        );
+    }
+
+    #[test]
+    fn extracts_all_meta_lowercasing_keys() {
+        let html = html_from_bytes(MIXED_CASE_META_HTML).unwrap();
+        let meta = extract_all_meta(&html, true);
+
+        assert_eq!(
+            meta[0],
+            ("og:title".to_string(), "Night Market Guide".to_string())
+        );
+        assert_eq!(
+            meta[3],
+            (
+                "description".to_string(),
+                "Where To Eat, Street By Street".to_string()
+            )
+        );
+        assert_eq!(
+            meta[4],
+            (
+                "og:image".to_string(),
+                "https://example.com/Night-Market.JPG".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn finds_meta_through_generic_getters() {
+        let html = html_from_bytes(FORMAT_DETECTION_HTML).unwrap();
+
+        assert_eq!(
+            find_meta_by_name(&html, "format-detection").unwrap(),
+            "telephone=no, email=no"
+        );
+        assert_eq!(
+            find_meta_by_property(&html, "og:title").unwrap(),
+            "Contact the Harbour Ferry Office"
+        );
+        assert!(find_meta_by_name(&html, "og:title").is_none());
+        assert!(find_meta_by_property(&html, "format-detection").is_none());
+    }
+
+    #[test]
+    fn finds_meta_named_after_any_characters() {
+        let html = Html::parse_document(
+            r#"<meta name='say "hi"' content="Hello"><meta property="a\b" content="Backslash">"#,
+        );
+
+        assert_eq!(find_meta_by_name(&html, "say \"hi\"").unwrap(), "Hello");
+        assert_eq!(find_meta_by_property(&html, "a\\b").unwrap(), "Backslash");
+        assert!(find_meta_by_name(&html, "\"]").is_none());
+        assert!(find_meta_by_property(&html, "x\\").is_none());
+    }
+
+    #[test]
+    fn finds_base_href_and_target() {
+        let html = html_from_bytes(BASE_ELEMENT_HTML).unwrap();
+        let (href, target) = find_base(&html).unwrap();
+
+        assert_eq!(href.unwrap().as_str(), "https://cdn.example.com/assets/");
+        assert_eq!(target.unwrap(), "_blank");
+        assert!(find_base(&html_from_bytes(APPLICATION_NAME_HTML).unwrap()).is_none());
+    }
+
+    #[test]
+    fn resolves_relative_base_against_document_url() {
+        let html = Html::parse_document(r#"<head><base href="/static/"></head>"#);
+        let document_url = Url::parse("https://example.com/posts/1").unwrap();
+
+        assert_eq!(find_base(&html), Some((None, None)));
+        assert!(document_base_url(&html, None).is_none());
+        assert_eq!(
+            document_base_url(&html, Some(&document_url))
+                .unwrap()
+                .as_str(),
+            "https://example.com/static/"
+        );
+        assert_eq!(
+            document_base_url(&Html::new_document(), Some(&document_url)),
+            Some(document_url)
+        );
+    }
+
+    #[test]
+    fn normalizes_whitespace_in_inner_html_and_meta_values() {
+        let html = html_from_bytes(MULTILINE_TEXT_HTML).unwrap();
+
+        assert_eq!(first_inner_html(&html, "title").unwrap(), "Hello World");
+        assert_eq!(find_meta_by_property(&html, "og:locale").unwrap(), "en_US");
+        assert_eq!(normalize_whitespace("\n  a \t\n b  "), "a b");
+    }
+
+    #[test]
+    fn parses_srcset_candidates() {
+        let candidates = parse_srcset(
+            "hero.jpg, hero-2x.jpg 2x,, /w_800,h_600/hero.jpg 800w, hero-tall.jpg 600h, hero.webp,",
+        );
+
+        assert_eq!(
+            candidates,
+            vec![
+                SrcsetCandidate {
+                    url: "hero.jpg".to_string(),
+                    width: None,
+                    density: None,
+                },
+                SrcsetCandidate {
+                    url: "hero-2x.jpg".to_string(),
+                    width: None,
+                    density: Some(2.0),
+                },
+                SrcsetCandidate {
+                    url: "/w_800,h_600/hero.jpg".to_string(),
+                    width: Some(800),
+                    density: None,
+                },
+                SrcsetCandidate {
+                    url: "hero.webp".to_string(),
+                    width: None,
+                    density: None,
+                },
+            ]
+        );
+        assert!(parse_srcset("  ").is_empty());
+    }
+
+    #[test]
+    fn picks_largest_srcset_candidate() {
+        let widths = parse_srcset("a.jpg 640w, b.jpg 1920w, c.jpg 1280w");
+        let densities = parse_srcset("a.jpg, b.jpg 3x, c.jpg 1.5x");
+
+        assert_eq!(best_srcset_candidate(&widths).unwrap().url, "b.jpg");
+        assert_eq!(best_srcset_candidate(&densities).unwrap().url, "b.jpg");
+        assert!(best_srcset_candidate(&[]).is_none());
+    }
+
+    #[test]
+    fn finds_preloaded_hero_image() {
+        let html = html_from_bytes(PRELOAD_IMAGE_HTML).unwrap();
+
+        assert_eq!(
+            find_preload_image(&html).unwrap(),
+            "https://res.example.com/image/upload/w_1920,c_fill/spring-hero.jpg"
+        );
+        assert!(find_preload_image(&html_from_bytes(APPLICATION_NAME_HTML).unwrap()).is_none());
+    }
 }
@@ -0,0 +1,292 @@
+//! Image dimensions
+//!
+//! Reads the dimensions of PNG, JPEG, WebP and GIF images from their headers,
+//! so they can be known without downloading the whole image.
+use reqwest::header::RANGE;
+use reqwest::Client;
+
+use crate::LinkPreview;
+
+/// Maximum amount of bytes read from an image looking for its dimensions.
+/// JPEG images may hold large metadata segments (e.g. EXIF thumbnails) before
+/// the frame header declaring them.
+const MAX_IMAGE_HEADER_BYTES: usize = 64 * 1024;
+
+impl LinkPreview {
+    /// Retrieves the width and height of the preview image.
+    ///
+    /// When the page doesn't declare both, the first bytes of `image_url` are
+    /// fetched using the provided `client` until its dimensions are read,
+    /// reading up to 64 KiB. `None` is returned when the image can't be
+    /// fetched, its format isn't supported or its header isn't found within
+    /// that limit.
+    pub async fn resolve_image_dimensions(&self, client: &Client) -> Option<(u32, u32)> {
+        if let Some(image) = &self.image {
+            if let (Some(width), Some(height)) = (image.width, image.height) {
+                return Some((width, height));
+            }
+        }
+
+        let image_url = self
+            .image_url
+            .as_ref()
+            .or_else(|| self.image.as_ref().map(|image| &image.url))?;
+        let mut resp = client
+            .get(image_url.as_str())
+            .header(RANGE, format!("bytes=0-{}", MAX_IMAGE_HEADER_BYTES - 1))
+            .send()
+            .await
+            .ok()?
+            .error_for_status()
+            .ok()?;
+        let mut bytes: Vec<u8> = Vec::new();
+
+        while let Some(chunk) = resp.chunk().await.ok()? {
+            let remaining = MAX_IMAGE_HEADER_BYTES - bytes.len();
+
+            bytes.extend_from_slice(&chunk[..chunk.len().min(remaining)]);
+
+            if let Some(dimensions) = image_dimensions(&bytes) {
+                return Some(dimensions);
+            }
+
+            if bytes.len() >= MAX_IMAGE_HEADER_BYTES {
+                break;
+            }
+        }
+
+        None
+    }
+}
+
+/// Reads the width and height of a PNG, JPEG, WebP or GIF image from the
+/// first bytes of the file, `None` when the format isn't recognized or the
+/// bytes end before the dimensions are declared
+pub fn image_dimensions(bytes: &[u8]) -> Option<(u32, u32)> {
+    if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+        return png_dimensions(bytes);
+    }
+
+    if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+        return Some((u16_le(bytes, 6)?.into(), u16_le(bytes, 8)?.into()));
+    }
+
+    if bytes.starts_with(b"\xff\xd8") {
+        return jpeg_dimensions(bytes);
+    }
+
+    if bytes.starts_with(b"RIFF") && bytes.get(8..12) == Some(b"WEBP") {
+        return webp_dimensions(bytes);
+    }
+
+    None
+}
+
+/// Reads the dimensions from the `IHDR` chunk, which comes right after the
+/// signature
+fn png_dimensions(bytes: &[u8]) -> Option<(u32, u32)> {
+    if bytes.get(12..16) != Some(b"IHDR") {
+        return None;
+    }
+
+    Some((u32_be(bytes, 16)?, u32_be(bytes, 20)?))
+}
+
+/// Walks the segments of a JPEG image up to the first Start Of Frame (SOF)
+/// segment, which declares the dimensions
+fn jpeg_dimensions(bytes: &[u8]) -> Option<(u32, u32)> {
+    let mut offset = 2;
+
+    loop {
+        if *bytes.get(offset)? != 0xff {
+            return None;
+        }
+
+        let marker = *bytes.get(offset + 1)?;
+
+        match marker {
+            // Fill bytes preceding a marker
+            0xff => offset += 1,
+            // Markers without a segment
+            0x01 | 0xd0..=0xd8 => offset += 2,
+            // SOF markers, except DHT (0xc4), JPG (0xc8) and DAC (0xcc)
+            0xc0..=0xcf if !matches!(marker, 0xc4 | 0xc8 | 0xcc) => {
+                let height = u16_be(bytes, offset + 5)?;
+                let width = u16_be(bytes, offset + 7)?;
+
+                return Some((width.into(), height.into()));
+            }
+            // End of image or start of scan, no frame was declared
+            0xd9 | 0xda => return None,
+            _ => offset += 2 + usize::from(u16_be(bytes, offset + 2)?),
+        }
+    }
+}
+
+/// Reads the dimensions from the first chunk of a WebP image, which is either
+/// a lossy (`VP8 `), lossless (`VP8L`) or extended (`VP8X`) one
+fn webp_dimensions(bytes: &[u8]) -> Option<(u32, u32)> {
+    match bytes.get(12..16)? {
+        b"VP8 " => {
+            if bytes.get(23..26) != Some(b"\x9d\x01\x2a") {
+                return None;
+            }
+
+            let width = u16_le(bytes, 26)? & 0x3fff;
+            let height = u16_le(bytes, 28)? & 0x3fff;
+
+            Some((width.into(), height.into()))
+        }
+        b"VP8L" => {
+            if *bytes.get(20)? != 0x2f {
+                return None;
+            }
+
+            let bits = u32::from_le_bytes(bytes.get(21..25)?.try_into().ok()?);
+
+            Some(((bits & 0x3fff) + 1, ((bits >> 14) & 0x3fff) + 1))
+        }
+        b"VP8X" => Some((u24_le(bytes, 24)? + 1, u24_le(bytes, 27)? + 1)),
+        _ => None,
+    }
+}
+
+fn u16_le(bytes: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_le_bytes(
+        bytes.get(offset..offset + 2)?.try_into().ok()?,
+    ))
+}
+
+fn u16_be(bytes: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_be_bytes(
+        bytes.get(offset..offset + 2)?.try_into().ok()?,
+    ))
+}
+
+fn u24_le(bytes: &[u8], offset: usize) -> Option<u32> {
+    let bytes = bytes.get(offset..offset + 3)?;
+
+    Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], 0]))
+}
+
+fn u32_be(bytes: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(
+        bytes.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::Client;
+    use url::Url;
+
+    use crate::mock::{MockResponse, MockServer};
+    use crate::providers::og::ImageMeta;
+    use crate::LinkPreview;
+
+    use super::image_dimensions;
+
+    /// PNG signature and `IHDR` chunk of a 1200x630 image
+    const PNG_HEADER: &[u8] = b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR\x00\x00\x04\xb0\x00\x00\x02\x76\x08\x06\x00\x00\x00";
+
+    /// JPEG image of 640x480 declaring an `APP0` segment before its frame
+    const JPEG_HEADER: &[u8] = b"\xff\xd8\xff\xe0\x00\x10JFIF\x00\x01\x01\x00\x00\x01\x00\x01\x00\x00\xff\xc0\x00\x11\x08\x01\xe0\x02\x80\x03\x01\x22\x00";
+
+    #[test]
+    fn reads_png_and_jpeg_dimensions() {
+        assert_eq!(image_dimensions(PNG_HEADER), Some((1200, 630)));
+        assert_eq!(image_dimensions(JPEG_HEADER), Some((640, 480)));
+        assert_eq!(image_dimensions(&PNG_HEADER[..20]), None);
+        assert_eq!(image_dimensions(&JPEG_HEADER[..20]), None);
+    }
+
+    #[test]
+    fn reads_gif_and_webp_dimensions() {
+        assert_eq!(
+            image_dimensions(b"GIF89a\x90\x01\x2c\x01\xf7\x00\x00"),
+            Some((400, 300))
+        );
+        assert_eq!(
+            image_dimensions(b"RIFF\x00\x00\x00\x00WEBPVP8 \x00\x00\x00\x00\x00\x00\x00\x9d\x01\x2a\x20\x03\x58\x02"),
+            Some((800, 600))
+        );
+        assert_eq!(
+            image_dimensions(b"RIFF\x00\x00\x00\x00WEBPVP8L\x00\x00\x00\x00\x2f\x3f\xc0\x00\x00"),
+            Some((64, 4))
+        );
+        assert_eq!(
+            image_dimensions(b"RIFF\x00\x00\x00\x00WEBPVP8X\x0a\x00\x00\x00\x10\x00\x00\x00\x7f\x07\x00\x37\x04\x00"),
+            Some((1920, 1080))
+        );
+        assert_eq!(image_dimensions(b"<svg></svg>"), None);
+    }
+
+    #[tokio::test]
+    async fn resolves_image_dimensions_from_image_header() {
+        let mut large_jpeg = b"\xff\xd8\xff\xe1\xff\xff".to_vec();
+
+        large_jpeg.resize(200 * 1024, 0);
+
+        let server = MockServer::start(vec![
+            (
+                "/hero.png",
+                MockResponse::new(200)
+                    .header("Content-Type", "image/png")
+                    .body(PNG_HEADER),
+            ),
+            (
+                "/large.jpg",
+                MockResponse::new(200)
+                    .header("Content-Type", "image/jpeg")
+                    .body(large_jpeg),
+            ),
+        ]);
+        let client = Client::new();
+        let preview = |path: &str| LinkPreview {
+            image_url: Some(Url::parse(&server.url(path)).unwrap()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            preview("/hero.png").resolve_image_dimensions(&client).await,
+            Some((1200, 630))
+        );
+        assert_eq!(
+            preview("/large.jpg")
+                .resolve_image_dimensions(&client)
+                .await,
+            None
+        );
+        assert_eq!(
+            preview("/missing.png")
+                .resolve_image_dimensions(&client)
+                .await,
+            None
+        );
+        assert!(server.requests()[0]
+            .to_lowercase()
+            .contains("range: bytes=0-65535\r\n"));
+    }
+
+    #[tokio::test]
+    async fn uses_declared_image_dimensions() {
+        let url = Url::parse("http://127.0.0.1:9/hero.png").unwrap();
+        let preview = LinkPreview {
+            image_url: Some(url.clone()),
+            image: Some(ImageMeta {
+                url,
+                secure_url: None,
+                width: Some(1200),
+                height: Some(630),
+                alt: None,
+                mime_type: None,
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            preview.resolve_image_dimensions(&Client::new()).await,
+            Some((1200, 630))
+        );
+    }
+}
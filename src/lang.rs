@@ -0,0 +1,45 @@
+//! Best-effort language detection from the extracted text, used when the
+//! document doesn't declare its locale.
+use whatlang::Lang;
+
+/// ISO 639-1 codes for the ISO 639-3 codes reported by `whatlang`
+const ISO_639_1_CODES: [(Lang, &str); 24] = [
+    (Lang::Ara, "ar"),
+    (Lang::Cmn, "zh"),
+    (Lang::Ces, "cs"),
+    (Lang::Dan, "da"),
+    (Lang::Deu, "de"),
+    (Lang::Ell, "el"),
+    (Lang::Eng, "en"),
+    (Lang::Fin, "fi"),
+    (Lang::Fra, "fr"),
+    (Lang::Heb, "he"),
+    (Lang::Hin, "hi"),
+    (Lang::Hun, "hu"),
+    (Lang::Ind, "id"),
+    (Lang::Ita, "it"),
+    (Lang::Jpn, "ja"),
+    (Lang::Kor, "ko"),
+    (Lang::Nld, "nl"),
+    (Lang::Pol, "pl"),
+    (Lang::Por, "pt"),
+    (Lang::Ron, "ro"),
+    (Lang::Rus, "ru"),
+    (Lang::Spa, "es"),
+    (Lang::Swe, "sv"),
+    (Lang::Tur, "tr"),
+];
+
+/// Detects the language of the provided text, retrieving its ISO 639-1 code
+/// (e.g. `fr`) or its ISO 639-3 code when it has none. Detections `whatlang`
+/// doesn't consider reliable are discarded.
+pub(crate) fn detect_language(text: &str) -> Option<String> {
+    let info = whatlang::detect(text).filter(|info| info.is_reliable())?;
+    let lang = info.lang();
+    let code = ISO_639_1_CODES
+        .iter()
+        .find(|(known, _)| *known == lang)
+        .map_or(lang.code(), |(_, code)| *code);
+
+    Some(code.to_string())
+}
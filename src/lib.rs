@@ -1,19 +1,110 @@
+pub mod breadcrumbs;
+pub mod canonical;
+pub mod config;
+pub mod dates;
+pub mod favicons;
+pub mod feeds;
 pub mod html;
+pub mod media;
+pub mod minimal;
 pub mod preview;
 pub mod profiles;
 pub mod providers;
+pub mod urls;
 
-pub use preview::{html_from_bytes, LinkPreview};
+pub use config::ExtractionConfig;
+pub use preview::{html_from_bytes, LinkPreview, PreviewBuilder};
+pub use providers::source::ExtractionSource;
+
+#[cfg(feature = "encoding")]
+mod encoding;
+
+#[cfg(feature = "json")]
+pub mod faq;
 
 #[cfg(feature = "fetch")]
 pub mod fetch;
 
+#[cfg(feature = "image")]
+pub mod image_size;
+
+#[cfg(feature = "lang-detect")]
+mod lang;
+
+#[cfg(all(test, feature = "fetch"))]
+mod mock;
+
 #[cfg(test)]
 mod tests {
+    pub const APPLICATION_NAME_HTML: &[u8] = include_bytes!("../html/application_name.html");
+    pub const APP_LINKS_HTML: &[u8] = include_bytes!("../html/app_links.html");
+    pub const ARTICLE_EXPIRATION_HTML: &[u8] = include_bytes!("../html/article_expiration.html");
+    pub const ARTICLE_PARAGRAPH_HTML: &[u8] = include_bytes!("../html/article_paragraph.html");
+    pub const AUTHOR_SOURCES_HTML: &[u8] = include_bytes!("../html/author_sources.html");
+    pub const BASE_ELEMENT_HTML: &[u8] = include_bytes!("../html/base_element.html");
+    pub const BODY_CANONICAL_HTML: &[u8] = include_bytes!("../html/body_canonical.html");
+    pub const BODY_OG_IMAGE_HTML: &[u8] = include_bytes!("../html/body_og_image.html");
+    pub const CANONICAL_MATCH_HTML: &[u8] = include_bytes!("../html/canonical_match.html");
+    pub const CANONICAL_MISMATCH_HTML: &[u8] = include_bytes!("../html/canonical_mismatch.html");
+    pub const COLOR_SCHEME_HTML: &[u8] = include_bytes!("../html/color_scheme.html");
+    pub const CONTENT_IMAGE_HTML: &[u8] = include_bytes!("../html/content_image.html");
+    pub const CONTENT_LANGUAGE_HTML: &[u8] = include_bytes!("../html/content_language.html");
+    pub const DUBLIN_CORE_HTML: &[u8] = include_bytes!("../html/dublin_core.html");
+    pub const DUBLIN_CORE_ONLY_HTML: &[u8] = include_bytes!("../html/dublin_core_only.html");
+    pub const EXTRACTION_TOGGLES_HTML: &[u8] = include_bytes!("../html/extraction_toggles.html");
+    pub const FAQ_PAGE_HTML: &[u8] = include_bytes!("../html/faq_page.html");
+    pub const FEEDS_HTML: &[u8] = include_bytes!("../html/feeds.html");
+    pub const FIGURE_CAPTION_HTML: &[u8] = include_bytes!("../html/figure_caption.html");
+    pub const FLICKR_PHOTO_HTML: &[u8] = include_bytes!("../html/flickr_photo.html");
+    pub const FORMAT_DETECTION_HTML: &[u8] = include_bytes!("../html/format_detection.html");
     pub const FULL_FEATURED_HTML: &[u8] = include_bytes!("../html/full_featured.html");
+    pub const HTML_ENTITIES_HTML: &[u8] = include_bytes!("../html/html_entities.html");
+    pub const IMAGE_ALT_HTML: &[u8] = include_bytes!("../html/image_alt.html");
+    pub const JSONLD_ARTICLE_HTML: &[u8] = include_bytes!("../html/jsonld_article.html");
+    pub const JSONLD_COMPETING_TYPES_HTML: &[u8] =
+        include_bytes!("../html/jsonld_competing_types.html");
+    pub const JSONLD_GRAPH_HTML: &[u8] = include_bytes!("../html/jsonld_graph.html");
+    pub const JSONLD_IMAGE_OBJECT_HTML: &[u8] = include_bytes!("../html/jsonld_image_object.html");
+    pub const KEYWORDS_HTML: &[u8] = include_bytes!("../html/keywords.html");
+    pub const LAST_MODIFIED_HTML: &[u8] = include_bytes!("../html/last_modified.html");
+    pub const LOGO_IMAGE_HTML: &[u8] = include_bytes!("../html/logo_image.html");
+    pub const MAPS_PLACE_HTML: &[u8] = include_bytes!("../html/maps_place.html");
+    pub const MEDIA_DURATION_HTML: &[u8] = include_bytes!("../html/media_duration.html");
+    pub const META_VALUE_ATTRIBUTE_HTML: &[u8] =
+        include_bytes!("../html/meta_value_attribute.html");
+    pub const MIXED_CASE_META_HTML: &[u8] = include_bytes!("../html/mixed_case_meta.html");
+    pub const MULTILINE_TEXT_HTML: &[u8] = include_bytes!("../html/multiline_text.html");
     pub const OG_COMPLIANT_HTML: &[u8] = include_bytes!("../html/og_compliant.html");
+    pub const OG_IMAGES_HTML: &[u8] = include_bytes!("../html/og_images.html");
+    pub const OG_IMAGE_META_HTML: &[u8] = include_bytes!("../html/og_image_meta.html");
+    pub const OG_STRUCTURED_HTML: &[u8] = include_bytes!("../html/og_structured.html");
+    pub const OG_VIDEO_HTML: &[u8] = include_bytes!("../html/og_video.html");
+    pub const PODCAST_EPISODE_HTML: &[u8] = include_bytes!("../html/podcast_episode.html");
+    pub const PRELOAD_IMAGE_HTML: &[u8] = include_bytes!("../html/preload_image.html");
+    pub const PRODUCT_HTML: &[u8] = include_bytes!("../html/product.html");
+    pub const PRODUCT_PREORDER_HTML: &[u8] = include_bytes!("../html/product_preorder.html");
+    pub const PROVIDER_ORDER_HTML: &[u8] = include_bytes!("../html/provider_order.html");
+    pub const PUBLISHED_TIMES_HTML: &[u8] = include_bytes!("../html/published_times.html");
+    pub const PX500_PHOTO_HTML: &[u8] = include_bytes!("../html/500px_photo.html");
+    pub const REFERRER_POLICY_HTML: &[u8] = include_bytes!("../html/referrer_policy.html");
+    pub const RELATIVE_FAVICON_HTML: &[u8] = include_bytes!("../html/relative_favicon.html");
+    pub const RELATIVE_URLS_HTML: &[u8] = include_bytes!("../html/relative_urls.html");
+    pub const RTL_DIRECTION_HTML: &[u8] = include_bytes!("../html/rtl_direction.html");
     pub const SCHEMA_COMPLIANT_HTML: &[u8] = include_bytes!("../html/schema_compliant.html");
+    pub const SIDEBAR_HEADING_HTML: &[u8] = include_bytes!("../html/sidebar_heading.html");
+    pub const SIZED_FAVICONS_HTML: &[u8] = include_bytes!("../html/sized_favicons.html");
+    pub const TIME_ELEMENT_HTML: &[u8] = include_bytes!("../html/time_element.html");
+    pub const TRACKING_OG_URL_HTML: &[u8] = include_bytes!("../html/tracking_og_url.html");
     pub const TWITTER_COMPLIANT_HTML: &[u8] = include_bytes!("../html/twitter_compliant.html");
+    pub const TWITTER_LABELS_HTML: &[u8] = include_bytes!("../html/twitter_labels.html");
+    pub const TWITTER_STATUS_HTML: &[u8] = include_bytes!("../html/twitter_status.html");
+    pub const TWITTER_URL_HTML: &[u8] = include_bytes!("../html/twitter_url.html");
+    pub const URL_BREADCRUMBS_HTML: &[u8] = include_bytes!("../html/url_breadcrumbs.html");
+    pub const VIDEO_OBJECT_HTML: &[u8] = include_bytes!("../html/video_object.html");
+    pub const VIMEO_VIDEO_HTML: &[u8] = include_bytes!("../html/vimeo_video.html");
+    pub const WIKIPEDIA_MOBILE_HTML: &[u8] = include_bytes!("../html/wikipedia_mobile.html");
+    pub const WINDOWS_1252_HTML: &[u8] = include_bytes!("../html/windows_1252.html");
+    pub const WORDPRESS_POST_HTML: &[u8] = include_bytes!("../html/wordpress_post.html");
     pub const YOUTUBE_VIDEO_HTML: &[u8] = include_bytes!("../html/youtube_sample.html");
 
     #[cfg(feature = "fetch")]
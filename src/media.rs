@@ -0,0 +1,154 @@
+//! Properties of the media object described by the document, such as the
+//! length of a video or a song.
+use scraper::Html;
+
+use crate::html::find_meta_tag;
+
+#[cfg(feature = "json")]
+use crate::providers::jsonld::{find_jsonld_entities, jsonld_str};
+
+/// Meta tags declaring the duration of the media object in seconds, by
+/// priority
+const DURATION_META_TAGS: [&str; 3] = ["video:duration", "music:duration", "og:video:duration"];
+
+/// Retrieves the duration of the media object described by the document, in
+/// seconds, in the following order:
+///
+/// - OpenGraph's `video:duration`, `music:duration` and `og:video:duration`
+///   meta tags, declared as an integer number of seconds
+/// - The ISO 8601 `duration` (e.g. `PT3M57S`) of the first JSON-LD entity
+///   declaring one, such as a `VideoObject` or an `AudioObject`
+///
+/// Values which can't be parsed are skipped.
+pub fn find_media_duration(html: &Html) -> Option<u32> {
+    DURATION_META_TAGS
+        .iter()
+        .find_map(|property| {
+            find_meta_tag(html, property).and_then(|value| value.trim().parse::<u32>().ok())
+        })
+        .or_else(|| find_jsonld_duration(html))
+}
+
+/// Retrieves the ISO 8601 `duration` of the first JSON-LD entity declaring a
+/// valid one, in seconds
+#[cfg(feature = "json")]
+fn find_jsonld_duration(html: &Html) -> Option<u32> {
+    find_jsonld_entities(html)
+        .iter()
+        .filter_map(|entity| jsonld_str(entity, "duration"))
+        .find_map(|value| parse_iso8601_duration(&value))
+}
+
+/// Retrieves the duration declared through JSON-LD, always `None` as
+/// JSON-LD isn't parsed without the `json` feature
+#[cfg(not(feature = "json"))]
+fn find_jsonld_duration(_html: &Html) -> Option<u32> {
+    None
+}
+
+/// Parses an ISO 8601 duration (e.g. `PT1H2M30S` or `P1DT2H`) into seconds.
+/// Fractions of a second are truncated, while years and months are not
+/// supported as their length varies.
+pub fn parse_iso8601_duration(value: &str) -> Option<u32> {
+    let value = value.trim().to_ascii_uppercase();
+    let value = value.strip_prefix('P')?;
+    let (date, time) = match value.split_once('T') {
+        Some((date, time)) if !time.is_empty() => (date, Some(time)),
+        Some(_) => return None,
+        None => (value, None),
+    };
+
+    if date.is_empty() && time.is_none() {
+        return None;
+    }
+
+    let mut seconds = sum_components(date, &[('W', 604_800), ('D', 86_400)])?;
+
+    if let Some(time) = time {
+        // Components saturate, so huge durations must not overflow here
+        seconds =
+            seconds.checked_add(sum_components(time, &[('H', 3_600), ('M', 60), ('S', 1)])?)?;
+    }
+
+    u32::try_from(seconds).ok()
+}
+
+/// Adds up the components (e.g. `2H30M`) of one part of an ISO 8601
+/// duration, where `units` lists the allowed designators in order along with
+/// their length in seconds
+fn sum_components(part: &str, units: &[(char, u64)]) -> Option<u64> {
+    let mut total: f64 = 0.0;
+    let mut number = String::new();
+    let mut remaining = units.iter();
+
+    for character in part.chars() {
+        if character.is_ascii_digit() || character == '.' || character == ',' {
+            number.push(if character == ',' { '.' } else { character });
+            continue;
+        }
+
+        let (_, length) = remaining.find(|(unit, _)| *unit == character)?;
+        let amount: f64 = number.parse().ok()?;
+
+        total += amount * *length as f64;
+        number.clear();
+    }
+
+    if !number.is_empty() {
+        return None;
+    }
+
+    Some(total as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use scraper::Html;
+
+    use crate::html_from_bytes;
+    use crate::tests::MEDIA_DURATION_HTML;
+
+    use super::{find_media_duration, parse_iso8601_duration};
+
+    #[test]
+    fn finds_duration_from_meta_tag() {
+        let html = html_from_bytes(MEDIA_DURATION_HTML).unwrap();
+
+        assert_eq!(find_media_duration(&html), Some(1534));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn falls_back_to_jsonld_duration() {
+        let html = Html::parse_document(
+            r#"<html><head>
+                <meta property="music:duration" content="unknown">
+                <script type="application/ld+json">{"@type": "AudioObject", "duration": "PT4M12S"}</script>
+            </head></html>"#,
+        );
+
+        assert_eq!(find_media_duration(&html), Some(252));
+    }
+
+    #[test]
+    fn parses_iso8601_durations() {
+        assert_eq!(parse_iso8601_duration("PT3M57S"), Some(237));
+        assert_eq!(parse_iso8601_duration("PT1H"), Some(3_600));
+        assert_eq!(parse_iso8601_duration("P1DT2H0M5.8S"), Some(93_605));
+        assert_eq!(parse_iso8601_duration("pt90s"), Some(90));
+        assert_eq!(parse_iso8601_duration("P1W"), Some(604_800));
+        assert_eq!(parse_iso8601_duration("PT"), None);
+        assert_eq!(parse_iso8601_duration("P1M"), None);
+        assert_eq!(parse_iso8601_duration("PT5S3M"), None);
+        assert_eq!(parse_iso8601_duration("3:57"), None);
+    }
+
+    #[test]
+    fn rejects_overflowing_iso8601_durations() {
+        assert_eq!(
+            parse_iso8601_duration("P99999999999999999999DT99999999999999999999H"),
+            None
+        );
+        assert_eq!(parse_iso8601_duration("PT99999999999S"), None);
+    }
+}
@@ -0,0 +1,267 @@
+//! Lightweight Open Graph extraction for constrained environments
+//!
+//! `extract_meta_minimal` scans the raw document bytes for `<meta>` tags
+//! instead of building a DOM with `scraper`, trading accuracy for a much lower
+//! overhead. Compared to the full parser used by `LinkPreview::from` it:
+//!
+//! - Only reads `og:title`, `og:description`, `og:image`, `og:url`,
+//!   `og:site_name`, `og:locale` and `og:type`, with no fallback to Twitter Cards,
+//!   Schema.org or the document's content
+//! - Does not decode HTML entities in attribute values
+//! - Does not understand comments, `<script>` or `<template>` contents, so a
+//!   `<meta>` tag inside any of them is still picked up
+//! - Keeps the first occurrence of each property
+//! - Does not read structured properties such as `og:image:width`, so the
+//!   `image` metadata is left empty
+//! - Does not read `og:video` or the media duration
+//! - Does not read the document's language, as it's declared on the `<html>`
+//!   element rather than through a `<meta>` tag
+use url::Url;
+
+use crate::urls::strip_tracking_params;
+use crate::LinkPreview;
+
+/// Attribute name and value byte slices of a single `<meta>` tag
+type Attributes<'a> = Vec<(&'a [u8], &'a [u8])>;
+
+/// Extracts a `LinkPreview` from the Open Graph meta tags in the provided
+/// document bytes without parsing the whole document.
+///
+/// The `domain` and `canonical_url` are taken from `og:url`, and invalid UTF-8
+/// sequences are replaced instead of failing.
+pub fn extract_meta_minimal(bytes: &[u8]) -> LinkPreview {
+    let mut title: Option<String> = None;
+    let mut description: Option<String> = None;
+    let mut image_url: Option<String> = None;
+    let mut url: Option<String> = None;
+    let mut site_name: Option<String> = None;
+    let mut locale: Option<String> = None;
+    let mut content_type: Option<String> = None;
+
+    for attributes in MetaTags::new(bytes) {
+        let property = attribute(&attributes, b"property");
+        let content = attribute(&attributes, b"content");
+        let (Some(property), Some(content)) = (property, content) else {
+            continue;
+        };
+        let field = match property {
+            b"og:title" => &mut title,
+            b"og:description" => &mut description,
+            b"og:image" => &mut image_url,
+            b"og:url" => &mut url,
+            b"og:site_name" => &mut site_name,
+            b"og:locale" => &mut locale,
+            b"og:type" => &mut content_type,
+            _ => continue,
+        };
+
+        if field.is_none() {
+            *field = Some(String::from_utf8_lossy(content).into_owned());
+        }
+    }
+
+    let mut canonical_url = url.and_then(|url| Url::parse(&url).ok());
+
+    if let Some(canonical_url) = canonical_url.as_mut() {
+        strip_tracking_params(canonical_url);
+    }
+
+    LinkPreview {
+        title,
+        description,
+        domain: canonical_url
+            .as_ref()
+            .and_then(|url| url.domain().map(|domain| domain.to_string())),
+        image_url: image_url.and_then(|image_url| Url::parse(&image_url).ok()),
+        site_name,
+        canonical_url,
+        locale,
+        content_type,
+        ..Default::default()
+    }
+}
+
+/// Retrieves the value of the attribute `name`, compared case-insensitively
+fn attribute<'a>(attributes: &Attributes<'a>, name: &[u8]) -> Option<&'a [u8]> {
+    attributes
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, value)| *value)
+}
+
+/// Iterator over the attributes of every `<meta>` tag in a document
+struct MetaTags<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> MetaTags<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        MetaTags { bytes, position: 0 }
+    }
+
+    /// Finds the position right after the next `<meta` tag name
+    fn next_tag(&self) -> Option<usize> {
+        let bytes = self.bytes;
+
+        (self.position..bytes.len()).find_map(|start| {
+            let name_end = start + 5;
+            let is_meta = bytes[start] == b'<'
+                && bytes
+                    .get(start + 1..name_end)
+                    .is_some_and(|name| name.eq_ignore_ascii_case(b"meta"))
+                && bytes
+                    .get(name_end)
+                    .is_some_and(|byte| byte.is_ascii_whitespace() || matches!(*byte, b'/' | b'>'));
+
+            is_meta.then_some(name_end)
+        })
+    }
+
+    /// Reads attributes starting at `position` until the tag is closed,
+    /// returning them along with the position after the tag
+    fn read_attributes(&self, mut position: usize) -> (Attributes<'a>, usize) {
+        let bytes = self.bytes;
+        let len = bytes.len();
+        let mut attributes = Vec::new();
+
+        loop {
+            while position < len
+                && (bytes[position].is_ascii_whitespace() || bytes[position] == b'/')
+            {
+                position += 1;
+            }
+
+            if position >= len {
+                break;
+            }
+
+            if bytes[position] == b'>' {
+                position += 1;
+                break;
+            }
+
+            let name_start = position;
+
+            while position < len
+                && !bytes[position].is_ascii_whitespace()
+                && !matches!(bytes[position], b'=' | b'>' | b'/')
+            {
+                position += 1;
+            }
+
+            let name = &bytes[name_start..position];
+
+            while position < len && bytes[position].is_ascii_whitespace() {
+                position += 1;
+            }
+
+            if position >= len || bytes[position] != b'=' {
+                attributes.push((name, &bytes[position..position]));
+                continue;
+            }
+
+            position += 1;
+
+            while position < len && bytes[position].is_ascii_whitespace() {
+                position += 1;
+            }
+
+            let value = if position < len && matches!(bytes[position], b'"' | b'\'') {
+                let quote = bytes[position];
+                let value_start = position + 1;
+
+                position = value_start;
+
+                while position < len && bytes[position] != quote {
+                    position += 1;
+                }
+
+                let value = &bytes[value_start..position];
+
+                position = (position + 1).min(len);
+                value
+            } else {
+                let value_start = position;
+
+                while position < len
+                    && !bytes[position].is_ascii_whitespace()
+                    && bytes[position] != b'>'
+                {
+                    position += 1;
+                }
+
+                &bytes[value_start..position]
+            };
+
+            attributes.push((name, value));
+        }
+
+        (attributes, position)
+    }
+}
+
+impl<'a> Iterator for MetaTags<'a> {
+    type Item = Attributes<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.next_tag()?;
+        let (attributes, end) = self.read_attributes(start);
+
+        self.position = end;
+
+        Some(attributes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html_from_bytes;
+    use crate::tests::{FULL_FEATURED_HTML, OG_COMPLIANT_HTML};
+    use crate::LinkPreview;
+
+    use super::extract_meta_minimal;
+
+    #[test]
+    fn matches_full_extractor_on_og_compliant_html() {
+        let html = html_from_bytes(OG_COMPLIANT_HTML).unwrap();
+        let full = LinkPreview::from(&html);
+        let minimal = extract_meta_minimal(OG_COMPLIANT_HTML);
+
+        assert_eq!(
+            minimal,
+            LinkPreview {
+                image: None,
+                language: None,
+                ..full
+            }
+        );
+    }
+
+    #[test]
+    fn matches_full_extractor_og_fields_on_full_featured_html() {
+        let html = html_from_bytes(FULL_FEATURED_HTML).unwrap();
+        let full = LinkPreview::from(&html);
+        let minimal = extract_meta_minimal(FULL_FEATURED_HTML);
+
+        assert_eq!(minimal.title, full.title);
+        assert_eq!(minimal.description, full.description);
+        assert_eq!(minimal.image_url, full.image_url);
+        // The full extractor prefers `<link rel="canonical">` for the domain
+        assert_eq!(minimal.domain.unwrap(), "abetterweb.com");
+    }
+
+    #[test]
+    fn reads_unquoted_and_single_quoted_attributes() {
+        let html = b"<HTML><META property=og:title content='Rust &amp; Friends'><meta content=https://example.com/a.png property=\"og:image\"/><meta property=\"og:title\" content=\"Ignored\"></HTML>";
+        let minimal = extract_meta_minimal(html);
+
+        assert_eq!(minimal.title.unwrap(), "Rust &amp; Friends");
+        assert_eq!(
+            minimal.image_url.unwrap().to_string(),
+            "https://example.com/a.png"
+        );
+        assert!(minimal.description.is_none());
+        assert!(minimal.domain.is_none());
+    }
+}
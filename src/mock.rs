@@ -0,0 +1,142 @@
+//! Minimal HTTP/1.1 server used by the `fetch` tests so they don't depend on
+//! remote hosts.
+//!
+//! Every accepted request is recorded and answered with the response
+//! registered for its path, or `404` when none matches.
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+#[derive(Clone, Debug)]
+pub struct MockResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+    delay: Option<Duration>,
+}
+
+impl MockResponse {
+    pub fn new(status: u16) -> Self {
+        MockResponse {
+            status,
+            headers: Vec::new(),
+            body: Vec::new(),
+            delay: None,
+        }
+    }
+
+    /// A `200 OK` response carrying the provided HTML document
+    pub fn html(body: impl Into<Vec<u8>>) -> Self {
+        MockResponse::new(200)
+            .header("Content-Type", "text/html; charset=utf-8")
+            .body(body)
+    }
+
+    /// A `302 Found` response pointing to `location`
+    pub fn redirect(location: &str) -> Self {
+        MockResponse::new(302).header("Location", location)
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Waits for `delay` before writing the response
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+}
+
+pub struct MockServer {
+    address: String,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl MockServer {
+    pub fn start(routes: Vec<(&str, MockResponse)>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to bind mock server");
+        let address = format!("http://{}", listener.local_addr().unwrap());
+        let routes: Arc<HashMap<String, MockResponse>> = Arc::new(
+            routes
+                .into_iter()
+                .map(|(path, response)| (path.to_string(), response))
+                .collect(),
+        );
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&requests);
+
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let routes = Arc::clone(&routes);
+                let recorded = Arc::clone(&recorded);
+
+                thread::spawn(move || handle(stream, &routes, &recorded));
+            }
+        });
+
+        MockServer { address, requests }
+    }
+
+    /// Builds an absolute URL for `path` on this server
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{}", self.address, path)
+    }
+
+    /// Raw requests (request line and headers) received so far
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+fn handle(
+    mut stream: TcpStream,
+    routes: &HashMap<String, MockResponse>,
+    recorded: &Mutex<Vec<String>>,
+) {
+    let mut request: Vec<u8> = Vec::new();
+    let mut buffer = [0_u8; 1024];
+
+    while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+        match stream.read(&mut buffer) {
+            Ok(0) | Err(_) => break,
+            Ok(read) => request.extend_from_slice(&buffer[..read]),
+        }
+    }
+
+    let request = String::from_utf8_lossy(&request).to_string();
+    let path = request.split_whitespace().nth(1).unwrap_or("/").to_string();
+
+    recorded.lock().unwrap().push(request);
+
+    let not_found = MockResponse::new(404);
+    let response = routes.get(&path).unwrap_or(&not_found);
+
+    if let Some(delay) = response.delay {
+        thread::sleep(delay);
+    }
+
+    let mut head = format!(
+        "HTTP/1.1 {} Mock\r\nContent-Length: {}\r\nConnection: close\r\n",
+        response.status,
+        response.body.len()
+    );
+
+    for (name, value) in &response.headers {
+        head.push_str(&format!("{name}: {value}\r\n"));
+    }
+
+    head.push_str("\r\n");
+
+    let _ = stream.write_all(head.as_bytes());
+    let _ = stream.write_all(&response.body);
+}
@@ -0,0 +1,120 @@
+use scraper::Html;
+use url::Url;
+
+use crate::profiles::ProfileExt;
+use crate::providers::geo::Geo;
+use crate::providers::og::{find_og_tag, OpenGraphTag};
+use crate::LinkPreview;
+
+/// Separator between the place name and its address in the page title
+const PLACE_TITLE_SEPARATOR: &str = " · ";
+
+/// Google Maps place pages, usually reached by following the redirect of a
+/// shared `maps.app.goo.gl` link.
+///
+/// The place name is used as `title`, dropping the address appended to it.
+/// When the page doesn't declare its location, the coordinates are read from
+/// the `@latitude,longitude` segment of `og:url` or the `center` of the
+/// static map used as `og:image`.
+pub struct MapsProfile {}
+
+impl ProfileExt for MapsProfile {
+    fn extract(html: &Html) -> Option<LinkPreview> {
+        let mut link_preview = LinkPreview::from(html);
+
+        if let Some(title) = link_preview.title.as_mut() {
+            if let Some((name, _address)) = title.split_once(PLACE_TITLE_SEPARATOR) {
+                *title = name.trim().to_string();
+            }
+        }
+
+        if link_preview.geo.is_none() {
+            link_preview.geo = find_og_tag(html, OpenGraphTag::Url)
+                .and_then(|url| geo_from_place_url(&url))
+                .or_else(|| {
+                    link_preview
+                        .image_url
+                        .as_ref()
+                        .and_then(geo_from_static_map)
+                });
+        }
+
+        Some(link_preview)
+    }
+
+    fn fits(url: &Url) -> bool {
+        let Some(host) = url.host_str() else {
+            return false;
+        };
+
+        match host {
+            "maps.app.goo.gl" | "maps.google.com" => true,
+            "goo.gl" => url.path().starts_with("/maps"),
+            host => {
+                let host = host.strip_prefix("www.").unwrap_or(host);
+
+                host.starts_with("google.") && url.path().starts_with("/maps")
+            }
+        }
+    }
+}
+
+/// Reads the coordinates from the `@latitude,longitude,zoom` path segment of
+/// a place URL
+fn geo_from_place_url(url: &str) -> Option<Geo> {
+    let (_, position) = url.split_once('@')?;
+    let position = position.split('/').next()?;
+    let mut parts = position.splitn(3, ',');
+
+    Geo::new(parts.next()?.parse().ok()?, parts.next()?.parse().ok()?)
+}
+
+/// Reads the coordinates from the `center` query parameter of a static map
+fn geo_from_static_map(url: &Url) -> Option<Geo> {
+    url.query_pairs()
+        .find(|(name, _)| name == "center")
+        .and_then(|(_, center)| Geo::parse(&center))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html_from_bytes;
+    use crate::tests::MAPS_PLACE_HTML;
+
+    use super::*;
+
+    #[test]
+    fn test_maps_profile() {
+        let html = html_from_bytes(MAPS_PLACE_HTML).unwrap();
+
+        let url = Url::parse("https://www.google.com/maps/place/Casa+Verde/@38.7139,-9.1452,17z")
+            .expect("Failed to parse URL");
+        assert!(MapsProfile::fits(&url));
+
+        let preview = MapsProfile::extract(&html).unwrap();
+
+        assert_eq!(preview.title, Some("Casa Verde".to_string()));
+        assert_eq!(preview.site_name, Some("Google Maps".to_string()));
+        assert_eq!(preview.geo, Geo::new(38.7139, -9.1452));
+    }
+
+    #[test]
+    fn fits_share_links() {
+        let fits = |url: &str| MapsProfile::fits(&Url::parse(url).unwrap());
+
+        assert!(fits("https://maps.app.goo.gl/Xy12AbCdEfGh34"));
+        assert!(fits("https://goo.gl/maps/Xy12AbCdEfGh34"));
+        assert!(fits("https://www.google.co.uk/maps/place/Casa+Verde"));
+        assert!(!fits("https://www.google.com/search?q=maps"));
+    }
+
+    #[test]
+    fn reads_geo_from_static_map_center() {
+        let url = Url::parse(
+            "https://maps.google.com/maps/api/staticmap?center=38.7139%2C-9.1452&zoom=16",
+        )
+        .unwrap();
+
+        assert_eq!(geo_from_static_map(&url), Geo::new(38.7139, -9.1452));
+    }
+}
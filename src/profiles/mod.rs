@@ -3,12 +3,36 @@ use url::Url;
 
 use crate::LinkPreview;
 
+pub mod maps;
+pub mod photo;
+pub mod podcast;
+pub mod product;
+pub mod registry;
+pub mod twitter;
+pub mod video;
+pub mod vimeo;
+pub mod wikipedia;
+pub mod wordpress;
 pub mod youtube;
 
 pub trait ProfileExt: Send + Sync + Sized {
     /// Checks if the profile fits the given URL.
     fn fits(url: &Url) -> bool;
 
+    /// Checks if the profile fits the provided HTML, regardless of the URL it
+    /// was retrieved from. Defaults to `false`.
+    fn fits_content(_html: &Html) -> bool {
+        false
+    }
+
     /// Creates a `LinkPreview` from the provided HTML.
     fn extract(html: &Html) -> Option<LinkPreview>;
+
+    /// Retrieves the oEmbed endpoint whose response completes the
+    /// `LinkPreview` extracted from the provided HTML, e.g. when it lacks an
+    /// image. Fetching functions merge the response into the `LinkPreview`.
+    /// Defaults to `None`.
+    fn oembed_endpoint(_html: &Html, _preview: &LinkPreview) -> Option<Url> {
+        None
+    }
 }
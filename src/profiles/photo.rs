@@ -0,0 +1,136 @@
+use scraper::Html;
+use url::Url;
+
+#[cfg(feature = "json")]
+use serde_json::Value;
+
+use crate::profiles::ProfileExt;
+use crate::LinkPreview;
+
+#[cfg(feature = "json")]
+use crate::providers::jsonld::{find_jsonld_entity, jsonld_name, jsonld_str, jsonld_url};
+
+const PHOTO_HOSTING_DOMAINS: [&str; 2] = ["flickr.com", "500px.com"];
+
+/// Photo hosting pages from Flickr and 500px.
+///
+/// These pages describe the photo through a `Photograph` or `ImageObject`
+/// JSON-LD entity, which is preferred over the meta tags as it references
+/// the full resolution image and credits the photographer, who is used as
+/// `author`. JSON-LD is only read with the `json` feature.
+pub struct PhotoProfile {}
+
+impl ProfileExt for PhotoProfile {
+    fn extract(html: &Html) -> Option<LinkPreview> {
+        let link_preview = LinkPreview::from(html);
+
+        #[cfg(feature = "json")]
+        let link_preview = match find_jsonld_entity(html, "Photograph")
+            .or_else(|| find_jsonld_entity(html, "ImageObject"))
+        {
+            Some(photo) => complete_with_photo(link_preview, &photo),
+            None => link_preview,
+        };
+
+        Some(link_preview)
+    }
+
+    fn fits(url: &Url) -> bool {
+        url.host_str().is_some_and(|host| {
+            PHOTO_HOSTING_DOMAINS
+                .iter()
+                .any(|domain| host == *domain || host.ends_with(&format!(".{domain}")))
+        })
+    }
+}
+
+/// Completes the provided `LinkPreview` with the `Photograph` or
+/// `ImageObject` JSON-LD entity
+#[cfg(feature = "json")]
+fn complete_with_photo(mut link_preview: LinkPreview, photo: &Value) -> LinkPreview {
+    if let Some(title) = jsonld_str(photo, "name") {
+        link_preview.title = Some(title);
+    }
+
+    if let Some(description) = jsonld_str(photo, "description") {
+        link_preview.description = Some(description);
+    }
+
+    if let Some(image_url) = photo
+        .get("contentUrl")
+        .or_else(|| photo.get("image"))
+        .and_then(jsonld_url)
+        .and_then(|image_url| Url::parse(&image_url).ok())
+    {
+        link_preview.image_url = Some(image_url);
+    }
+
+    if let Some(author) = photo
+        .get("author")
+        .or_else(|| photo.get("creator"))
+        .and_then(jsonld_name)
+    {
+        link_preview.author = Some(author);
+    }
+
+    link_preview
+}
+
+#[cfg(all(test, feature = "json"))]
+mod tests {
+    use crate::html_from_bytes;
+    use crate::tests::{FLICKR_PHOTO_HTML, PX500_PHOTO_HTML};
+
+    use super::*;
+
+    #[test]
+    fn test_flickr_photo_profile() {
+        let html = html_from_bytes(FLICKR_PHOTO_HTML).unwrap();
+
+        let url = Url::parse("https://www.flickr.com/photos/analima/53012345678/")
+            .expect("Failed to parse URL");
+        assert!(PhotoProfile::fits(&url));
+
+        let preview = PhotoProfile::extract(&html).unwrap();
+
+        assert_eq!(
+            preview.title,
+            Some("Morning fog over the valley".to_string())
+        );
+        assert_eq!(
+            preview.description,
+            Some("Shot from the ridge just after sunrise, looking west.".to_string())
+        );
+        assert_eq!(
+            preview.image_url.map(|u| u.to_string()),
+            Some("https://live.staticflickr.com/65535/53012345678_9f8e7d6c5b_o.jpg".to_string())
+        );
+        assert_eq!(preview.author, Some("Ana Lima".to_string()));
+    }
+
+    #[test]
+    fn test_500px_photo_profile() {
+        let html = html_from_bytes(PX500_PHOTO_HTML).unwrap();
+
+        let url = Url::parse("https://500px.com/photo/1012345678/shibuya-crossing-at-night")
+            .expect("Failed to parse URL");
+        assert!(PhotoProfile::fits(&url));
+
+        let preview = PhotoProfile::extract(&html).unwrap();
+
+        assert_eq!(preview.title, Some("Shibuya Crossing at Night".to_string()));
+        assert_eq!(
+            preview.image_url.map(|u| u.to_string()),
+            Some("https://drscdn.500px.org/photo/1012345678/m%3D2048/v2?sig=4e5f6a7b".to_string())
+        );
+        assert_eq!(preview.author, Some("Kenji Watanabe".to_string()));
+        assert_eq!(preview.domain, Some("500px.com".to_string()));
+    }
+
+    #[test]
+    fn does_not_fit_other_hosts() {
+        let url = Url::parse("https://notflickr.com/photos/1").unwrap();
+
+        assert!(!PhotoProfile::fits(&url));
+    }
+}
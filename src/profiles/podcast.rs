@@ -0,0 +1,133 @@
+use scraper::Html;
+use url::Url;
+
+#[cfg(feature = "json")]
+use serde_json::Value;
+
+use crate::profiles::ProfileExt;
+use crate::LinkPreview;
+
+#[cfg(feature = "json")]
+use crate::providers::jsonld::{find_jsonld_entity, jsonld_str, jsonld_url};
+
+#[cfg(feature = "json")]
+const PODCAST_EPISODE_TYPE: &str = "PodcastEpisode";
+
+/// Podcast episode pages, such as the ones from Apple Podcasts, or any page
+/// declaring a `PodcastEpisode` JSON-LD entity.
+///
+/// The episode is read from the JSON-LD entity when available: its series
+/// name is used as `site_name` and its media enclosure as `audio_url`.
+/// JSON-LD is only read with the `json` feature.
+pub struct PodcastProfile {}
+
+impl ProfileExt for PodcastProfile {
+    fn extract(html: &Html) -> Option<LinkPreview> {
+        let link_preview = LinkPreview::from(html);
+
+        #[cfg(feature = "json")]
+        let link_preview = match find_jsonld_entity(html, PODCAST_EPISODE_TYPE) {
+            Some(episode) => complete_with_episode(link_preview, &episode),
+            None => link_preview,
+        };
+
+        Some(link_preview)
+    }
+
+    fn fits(url: &Url) -> bool {
+        url.host_str()
+            .is_some_and(|host| host == "podcasts.apple.com")
+    }
+
+    #[cfg(feature = "json")]
+    fn fits_content(html: &Html) -> bool {
+        find_jsonld_entity(html, PODCAST_EPISODE_TYPE).is_some()
+    }
+}
+
+/// Completes the provided `LinkPreview` with the `PodcastEpisode` JSON-LD
+/// entity
+#[cfg(feature = "json")]
+fn complete_with_episode(mut link_preview: LinkPreview, episode: &Value) -> LinkPreview {
+    if let Some(title) = jsonld_str(episode, "name") {
+        link_preview.title = Some(title);
+    }
+
+    if link_preview.description.is_none() {
+        link_preview.description = jsonld_str(episode, "description");
+    }
+
+    if let Some(show) = episode
+        .get("partOfSeries")
+        .and_then(|series| jsonld_str(series, "name"))
+    {
+        link_preview.site_name = Some(show);
+    }
+
+    if let Some(artwork) = episode
+        .get("image")
+        .and_then(jsonld_url)
+        .and_then(|image_url| Url::parse(&image_url).ok())
+    {
+        link_preview.image_url = Some(artwork);
+    }
+
+    if let Some(audio_url) = episode
+        .get("associatedMedia")
+        .and_then(jsonld_url)
+        .and_then(|audio_url| Url::parse(&audio_url).ok())
+    {
+        link_preview.audio_url = Some(audio_url);
+    }
+
+    link_preview
+}
+
+#[cfg(all(test, feature = "json"))]
+mod tests {
+    use crate::html_from_bytes;
+    use crate::tests::{OG_COMPLIANT_HTML, PODCAST_EPISODE_HTML};
+
+    use super::*;
+
+    #[test]
+    fn test_podcast_profile() {
+        let html = html_from_bytes(PODCAST_EPISODE_HTML).unwrap();
+
+        let url = Url::parse(
+            "https://podcasts.apple.com/us/podcast/the-long-way-home/id1500000000?i=1000600000000",
+        )
+        .expect("Failed to parse URL");
+        assert!(PodcastProfile::fits(&url));
+        assert!(PodcastProfile::fits_content(&html));
+
+        let preview = PodcastProfile::extract(&html).unwrap();
+
+        assert_eq!(
+            preview.title,
+            Some("Episode 42: The Long Way Home".to_string())
+        );
+        assert_eq!(preview.site_name, Some("Field Recordings".to_string()));
+        assert_eq!(
+            preview.image_url.map(|u| u.to_string()),
+            Some(
+                "https://is1-ssl.mzstatic.com/image/thumb/Podcasts/field-recordings/3000x3000bb.jpg"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            preview.audio_url.map(|u| u.to_string()),
+            Some("https://traffic.example-cdn.com/field-recordings/episode-42.mp3".to_string())
+        );
+        assert_eq!(preview.domain, Some("podcasts.apple.com".to_string()));
+    }
+
+    #[test]
+    fn does_not_fit_pages_without_podcast_episodes() {
+        let html = html_from_bytes(OG_COMPLIANT_HTML).unwrap();
+        let url = Url::parse("https://open.spotify.com/episode/1").unwrap();
+
+        assert!(!PodcastProfile::fits(&url));
+        assert!(!PodcastProfile::fits_content(&html));
+    }
+}
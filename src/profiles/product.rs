@@ -0,0 +1,233 @@
+use scraper::Html;
+use url::Url;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "json")]
+use serde_json::Value;
+
+use crate::profiles::ProfileExt;
+use crate::LinkPreview;
+
+#[cfg(feature = "json")]
+use crate::providers::jsonld::{find_jsonld_entity, jsonld_name, jsonld_str, jsonld_url};
+
+#[cfg(feature = "json")]
+const PRODUCT_TYPE: &str = "Product";
+
+/// Schema.org `ItemAvailability` values along with the status each one is
+/// normalized to
+#[cfg(feature = "json")]
+const AVAILABILITY_STATUSES: [(&str, &str); 10] = [
+    ("InStock", "InStock"),
+    ("InStoreOnly", "InStock"),
+    ("OnlineOnly", "InStock"),
+    ("LimitedAvailability", "InStock"),
+    ("OutOfStock", "OutOfStock"),
+    ("SoldOut", "OutOfStock"),
+    ("Discontinued", "OutOfStock"),
+    ("PreOrder", "PreOrder"),
+    ("PreSale", "PreOrder"),
+    ("BackOrder", "PreOrder"),
+];
+
+/// A product for sale as declared by a `Product` JSON-LD entity
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Product {
+    pub name: Option<String>,
+    /// First of the product's images
+    pub image_url: Option<Url>,
+    /// Price of the first offer as declared, e.g. `129.00`. The lowest price
+    /// is used for an `AggregateOffer`.
+    pub price: Option<String>,
+    /// ISO 4217 currency code of the first offer, e.g. `EUR`
+    pub currency: Option<String>,
+    /// Availability of the first offer as declared, usually a Schema.org
+    /// `ItemAvailability` URL such as `https://schema.org/InStock`
+    pub availability: Option<String>,
+    pub brand: Option<String>,
+}
+
+/// Retrieves the product declared by the first `Product` JSON-LD entity of
+/// the provided `Html` instance.
+///
+/// Offer details (price, currency and availability) are read from the first
+/// offer when `offers` holds many of them.
+#[cfg(feature = "json")]
+pub fn find_product(html: &Html) -> Option<Product> {
+    let product = find_jsonld_entity(html, PRODUCT_TYPE)?;
+    let offer = product.get("offers").and_then(|offers| match offers {
+        Value::Array(offers) => offers.first(),
+        offer => Some(offer),
+    });
+
+    Some(Product {
+        name: jsonld_str(&product, "name"),
+        image_url: product
+            .get("image")
+            .and_then(jsonld_url)
+            .and_then(|image_url| Url::parse(&image_url).ok()),
+        price: offer.and_then(|offer| {
+            offer
+                .get("price")
+                .or_else(|| offer.get("lowPrice"))
+                .and_then(|price| match price {
+                    Value::String(price) => Some(price.to_string()),
+                    Value::Number(price) => Some(price.to_string()),
+                    _ => None,
+                })
+        }),
+        currency: offer.and_then(|offer| jsonld_str(offer, "priceCurrency")),
+        availability: offer.and_then(|offer| jsonld_str(offer, "availability")),
+        brand: product.get("brand").and_then(jsonld_name),
+    })
+}
+
+/// Retrieves the availability of the product declared by the first
+/// `Product` JSON-LD entity, read from its first offer and normalized to
+/// either `InStock`, `OutOfStock` or `PreOrder`.
+///
+/// Schema.org `ItemAvailability` values are accepted either as URLs (e.g.
+/// `https://schema.org/InStock`) or as bare names, compared
+/// case-insensitively. Values which don't map to any of the statuses, such
+/// as `Reserved`, are treated as missing.
+#[cfg(feature = "json")]
+pub fn find_availability(html: &Html) -> Option<String> {
+    let availability = find_product(html)?.availability?;
+    let name = availability.trim().rsplit('/').next().unwrap_or_default();
+
+    AVAILABILITY_STATUSES
+        .iter()
+        .find(|(value, _)| value.eq_ignore_ascii_case(name))
+        .map(|(_, status)| status.to_string())
+}
+
+/// Store pages declaring a `Product` JSON-LD entity, as Shopify and most
+/// e-commerce platforms do.
+///
+/// The product's name and image are preferred over the meta tags, as stores
+/// usually decorate the `og:title` with the store name and scale down the
+/// `og:image`. Use `find_product` for the price, availability and brand. As
+/// the product is read from JSON-LD, the profile only fits pages with the
+/// `json` feature.
+pub struct ProductProfile {}
+
+impl ProfileExt for ProductProfile {
+    fn extract(html: &Html) -> Option<LinkPreview> {
+        let link_preview = LinkPreview::from(html);
+
+        #[cfg(feature = "json")]
+        let link_preview = match find_product(html) {
+            Some(product) => complete_with_product(link_preview, product),
+            None => link_preview,
+        };
+
+        Some(link_preview)
+    }
+
+    fn fits(_url: &Url) -> bool {
+        false
+    }
+
+    #[cfg(feature = "json")]
+    fn fits_content(html: &Html) -> bool {
+        find_jsonld_entity(html, PRODUCT_TYPE).is_some()
+    }
+}
+
+/// Completes the provided `LinkPreview` with the name and image of `product`
+#[cfg(feature = "json")]
+fn complete_with_product(mut link_preview: LinkPreview, product: Product) -> LinkPreview {
+    if let Some(title) = product.name {
+        link_preview.title = Some(title);
+    }
+
+    if let Some(image_url) = product.image_url {
+        link_preview.image_url = Some(image_url);
+    }
+
+    link_preview
+}
+
+#[cfg(all(test, feature = "json"))]
+mod tests {
+    use crate::html_from_bytes;
+    use crate::tests::{OG_COMPLIANT_HTML, PRODUCT_HTML, PRODUCT_PREORDER_HTML};
+
+    use super::*;
+
+    #[test]
+    fn test_product_profile() {
+        let html = html_from_bytes(PRODUCT_HTML).unwrap();
+
+        assert!(ProductProfile::fits_content(&html));
+
+        let preview = ProductProfile::extract(&html).unwrap();
+
+        assert_eq!(preview.title, Some("Alpine Merino Hoodie".to_string()));
+        assert_eq!(
+            preview.image_url.map(|u| u.to_string()),
+            Some("https://northbound.example.com/cdn/shop/products/hoodie-front.jpg".to_string())
+        );
+        assert_eq!(preview.site_name, Some("Northbound Outfitters".to_string()));
+    }
+
+    #[test]
+    fn finds_product_details() {
+        let html = html_from_bytes(PRODUCT_HTML).unwrap();
+        let product = find_product(&html).unwrap();
+
+        assert_eq!(product.name, Some("Alpine Merino Hoodie".to_string()));
+        assert_eq!(product.price, Some("129.00".to_string()));
+        assert_eq!(product.currency, Some("EUR".to_string()));
+        assert_eq!(
+            product.availability,
+            Some("https://schema.org/InStock".to_string())
+        );
+        assert_eq!(product.brand, Some("Northbound".to_string()));
+    }
+
+    #[test]
+    fn reads_numeric_price_from_aggregate_offer() {
+        let html = Html::parse_document(
+            r#"<script type="application/ld+json">{"@type": ["Product"], "name": "Trail Mug", "brand": "Northbound", "offers": {"@type": "AggregateOffer", "lowPrice": 18.5, "priceCurrency": "USD"}}</script>"#,
+        );
+        let product = find_product(&html).unwrap();
+
+        assert_eq!(product.price, Some("18.5".to_string()));
+        assert_eq!(product.currency, Some("USD".to_string()));
+        assert_eq!(product.brand, Some("Northbound".to_string()));
+        assert!(product.availability.is_none());
+    }
+
+    #[test]
+    fn finds_normalized_availability() {
+        let html = html_from_bytes(PRODUCT_HTML).unwrap();
+
+        assert_eq!(find_availability(&html), Some("InStock".to_string()));
+
+        let html = html_from_bytes(PRODUCT_PREORDER_HTML).unwrap();
+
+        assert_eq!(find_availability(&html), Some("PreOrder".to_string()));
+        assert_eq!(find_product(&html).unwrap().price, Some("449".to_string()));
+
+        let html = Html::parse_document(
+            r#"<script type="application/ld+json">{"@type": "Product", "offers": {"availability": "soldout"}}</script>"#,
+        );
+
+        assert_eq!(find_availability(&html), Some("OutOfStock".to_string()));
+
+        let html = html_from_bytes(OG_COMPLIANT_HTML).unwrap();
+
+        assert!(find_availability(&html).is_none());
+    }
+
+    #[test]
+    fn does_not_fit_pages_without_products() {
+        let html = html_from_bytes(OG_COMPLIANT_HTML).unwrap();
+
+        assert!(!ProductProfile::fits_content(&html));
+        assert!(find_product(&html).is_none());
+    }
+}
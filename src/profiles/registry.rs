@@ -0,0 +1,209 @@
+//! Selection of the profile fitting a page
+//!
+//! `ProfileExt` is made of associated functions, so profiles are registered
+//! through `ProfileEntry`, an object-safe adapter holding pointers to them.
+use scraper::Html;
+use url::Url;
+
+use crate::profiles::maps::MapsProfile;
+use crate::profiles::photo::PhotoProfile;
+use crate::profiles::podcast::PodcastProfile;
+use crate::profiles::product::ProductProfile;
+use crate::profiles::twitter::TwitterProfile;
+use crate::profiles::video::VideoProfile;
+use crate::profiles::vimeo::VimeoProfile;
+use crate::profiles::wikipedia::WikipediaProfile;
+use crate::profiles::wordpress::WordPressProfile;
+use crate::profiles::youtube::YouTubeProfile;
+use crate::profiles::ProfileExt;
+use crate::LinkPreview;
+
+/// Profiles shipped with the crate, in order of preference. Profiles fitting
+/// specific hosts come first, followed by the ones fitting content found on
+/// any site.
+pub const DEFAULT_PROFILES: [ProfileEntry; 10] = [
+    ProfileEntry::of::<YouTubeProfile>(),
+    ProfileEntry::of::<TwitterProfile>(),
+    ProfileEntry::of::<VimeoProfile>(),
+    ProfileEntry::of::<PodcastProfile>(),
+    ProfileEntry::of::<PhotoProfile>(),
+    ProfileEntry::of::<MapsProfile>(),
+    ProfileEntry::of::<ProductProfile>(),
+    ProfileEntry::of::<VideoProfile>(),
+    ProfileEntry::of::<WikipediaProfile>(),
+    ProfileEntry::of::<WordPressProfile>(),
+];
+
+/// A registered `ProfileExt`
+#[derive(Clone, Copy, Debug)]
+pub struct ProfileEntry {
+    fits: fn(&Url) -> bool,
+    fits_content: fn(&Html) -> bool,
+    extract: fn(&Html) -> Option<LinkPreview>,
+    oembed_endpoint: fn(&Html, &LinkPreview) -> Option<Url>,
+}
+
+impl ProfileEntry {
+    /// Creates the entry of the profile `P`
+    pub const fn of<P: ProfileExt>() -> Self {
+        ProfileEntry {
+            fits: P::fits,
+            fits_content: P::fits_content,
+            extract: P::extract,
+            oembed_endpoint: P::oembed_endpoint,
+        }
+    }
+
+    /// Extracts a `LinkPreview` using the profile if it fits either the URL
+    /// or the document, along with the oEmbed endpoint completing it
+    fn extract(&self, url: &Url, html: &Html) -> Option<(LinkPreview, Option<Url>)> {
+        if (self.fits)(url) || (self.fits_content)(html) {
+            let preview = (self.extract)(html)?;
+            let oembed_endpoint = (self.oembed_endpoint)(html, &preview);
+
+            return Some((preview, oembed_endpoint));
+        }
+
+        None
+    }
+}
+
+/// An ordered list of profiles, the first one fitting a page being used to
+/// extract its `LinkPreview`.
+///
+/// The default registry holds `DEFAULT_PROFILES`.
+#[derive(Clone, Debug)]
+pub struct ProfileRegistry {
+    profiles: Vec<ProfileEntry>,
+}
+
+impl Default for ProfileRegistry {
+    fn default() -> Self {
+        ProfileRegistry {
+            profiles: DEFAULT_PROFILES.to_vec(),
+        }
+    }
+}
+
+impl ProfileRegistry {
+    /// Creates a registry holding `DEFAULT_PROFILES`
+    pub fn new() -> Self {
+        ProfileRegistry::default()
+    }
+
+    /// Creates a registry without any profile
+    pub fn empty() -> Self {
+        ProfileRegistry {
+            profiles: Vec::new(),
+        }
+    }
+
+    /// Registers the profile `P` ahead of the profiles already registered, so
+    /// it's preferred over them
+    pub fn register<P: ProfileExt>(mut self) -> Self {
+        self.profiles.insert(0, ProfileEntry::of::<P>());
+        self
+    }
+
+    /// Extracts a `LinkPreview` using the first profile fitting either `url`
+    /// or the document (`ProfileExt::fits` and `ProfileExt::fits_content`)
+    /// and extracting one, `None` when there's none
+    pub fn extract(&self, url: &Url, html: &Html) -> Option<LinkPreview> {
+        self.extract_with_oembed_endpoint(url, html)
+            .map(|(preview, _)| preview)
+    }
+
+    /// Same as `extract`, along with the oEmbed endpoint the profile used
+    /// completes the `LinkPreview` with (`ProfileExt::oembed_endpoint`)
+    pub fn extract_with_oembed_endpoint(
+        &self,
+        url: &Url,
+        html: &Html,
+    ) -> Option<(LinkPreview, Option<Url>)> {
+        self.profiles
+            .iter()
+            .find_map(|profile| profile.extract(url, html))
+    }
+
+    /// Same as `extract`, falling back to `LinkPreview::from` when no profile
+    /// fits
+    pub fn preview_for(&self, url: &Url, html: &Html) -> LinkPreview {
+        self.extract(url, html)
+            .unwrap_or_else(|| LinkPreview::from(html))
+    }
+}
+
+/// Creates a `LinkPreview` from the provided `Html` instance retrieved from
+/// `url`, using the first of the `DEFAULT_PROFILES` fitting either of them
+/// or `LinkPreview::from` when none does
+pub fn preview_for(url: &Url, html: &Html) -> LinkPreview {
+    ProfileRegistry::default().preview_for(url, html)
+}
+
+#[cfg(test)]
+mod tests {
+    use scraper::Html;
+    use url::Url;
+
+    use crate::html_from_bytes;
+    use crate::profiles::ProfileExt;
+    use crate::tests::{OG_COMPLIANT_HTML, YOUTUBE_VIDEO_HTML};
+    use crate::LinkPreview;
+
+    use super::{preview_for, ProfileRegistry};
+
+    struct ShoutingProfile {}
+
+    impl ProfileExt for ShoutingProfile {
+        fn extract(html: &Html) -> Option<LinkPreview> {
+            let mut link_preview = LinkPreview::from(html);
+
+            link_preview.title = link_preview.title.map(|title| title.to_uppercase());
+
+            Some(link_preview)
+        }
+
+        fn fits(url: &Url) -> bool {
+            url.host_str() == Some("www.youtube.com")
+        }
+    }
+
+    #[test]
+    fn selects_first_fitting_profile() {
+        let html = html_from_bytes(YOUTUBE_VIDEO_HTML).unwrap();
+        let url = Url::parse("https://youtu.be/61JHONRXhjs").unwrap();
+
+        assert_eq!(
+            preview_for(&url, &html).image_url.unwrap().as_str(),
+            "https://i.ytimg.com/vi/61JHONRXhjs/maxresdefault.jpg"
+        );
+    }
+
+    #[test]
+    fn falls_back_to_link_preview_when_no_profile_fits() {
+        let html = html_from_bytes(OG_COMPLIANT_HTML).unwrap();
+        let url = Url::parse("https://abetterweb.com/seo").unwrap();
+
+        assert_eq!(preview_for(&url, &html), LinkPreview::from(&html));
+        assert!(ProfileRegistry::empty().extract(&url, &html).is_none());
+    }
+
+    #[test]
+    fn prefers_registered_profiles() {
+        let html = html_from_bytes(YOUTUBE_VIDEO_HTML).unwrap();
+        let url = Url::parse("https://www.youtube.com/watch?v=61JHONRXhjs").unwrap();
+        let registry = ProfileRegistry::new().register::<ShoutingProfile>();
+
+        assert_eq!(
+            registry.preview_for(&url, &html).title.unwrap(),
+            "GOOGLE — YEAR IN SEARCH 2024"
+        );
+        assert_eq!(
+            ProfileRegistry::new()
+                .preview_for(&url, &html)
+                .title
+                .unwrap(),
+            "Google — Year in Search 2024"
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use scraper::Html;
+use url::Url;
+
+use crate::profiles::ProfileExt;
+use crate::providers::twitter::{find_twitter_tag, TwitterMetaTag};
+use crate::LinkPreview;
+
+/// Hosts serving Twitter, now X, pages
+const TWITTER_HOSTS: [&str; 5] = [
+    "twitter.com",
+    "www.twitter.com",
+    "mobile.twitter.com",
+    "x.com",
+    "www.x.com",
+];
+
+/// Site name suffixes appended to page titles, e.g. `Jane on X: "..." / X`
+const TITLE_SUFFIXES: [&str; 2] = [" / X", " / Twitter"];
+
+/// Generic titles served in place of the page's own, compared
+/// case-insensitively once curly apostrophes are replaced
+const BOILERPLATE_TITLES: [&str; 4] = [
+    "x",
+    "twitter",
+    "x. it's what's happening",
+    "twitter. it's what's happening",
+];
+
+/// Twitter (now X) pages, which are rendered client-side and declare their
+/// metadata mostly through Twitter Card tags, while OpenGraph tags often hold
+/// generic values for the whole site.
+///
+/// `twitter:title`, `twitter:description` and `twitter:image` take priority
+/// over any other source. The ` / X` site name suffix is dropped from the
+/// `title`, and generic titles such as `X. It’s what’s happening` are
+/// discarded.
+pub struct TwitterProfile {}
+
+impl ProfileExt for TwitterProfile {
+    fn extract(html: &Html) -> Option<LinkPreview> {
+        let mut link_preview = LinkPreview::from(html);
+
+        if let Some(title) = find_twitter_tag(html, TwitterMetaTag::Title) {
+            link_preview.title = Some(title);
+        }
+
+        if let Some(description) = find_twitter_tag(html, TwitterMetaTag::Description) {
+            link_preview.description = Some(description);
+        }
+
+        if let Some(image_url) = find_twitter_tag(html, TwitterMetaTag::Image)
+            .and_then(|image_url| Url::parse(&image_url).ok())
+        {
+            link_preview.image_url = Some(image_url);
+        }
+
+        link_preview.title = link_preview.title.as_deref().and_then(clean_title);
+
+        Some(link_preview)
+    }
+
+    fn fits(url: &Url) -> bool {
+        url.host_str().is_some_and(|host| {
+            TWITTER_HOSTS
+                .iter()
+                .any(|twitter_host| host.eq_ignore_ascii_case(twitter_host))
+        })
+    }
+}
+
+/// Removes the site name suffix from a page title, `None` when what remains
+/// is a generic title
+fn clean_title(title: &str) -> Option<String> {
+    let title = TITLE_SUFFIXES
+        .iter()
+        .find_map(|suffix| title.strip_suffix(suffix))
+        .unwrap_or(title)
+        .trim();
+    let generic = title.replace('’', "'").to_lowercase();
+
+    if title.is_empty() || BOILERPLATE_TITLES.contains(&generic.as_str()) {
+        return None;
+    }
+
+    Some(title.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html_from_bytes;
+    use crate::tests::TWITTER_STATUS_HTML;
+
+    use super::*;
+
+    #[test]
+    fn test_twitter_profile() {
+        let html = html_from_bytes(TWITTER_STATUS_HTML).unwrap();
+        let url = Url::parse("https://x.com/rustlang/status/1869736212233605510").unwrap();
+
+        assert!(TwitterProfile::fits(&url));
+
+        let preview = TwitterProfile::extract(&html).unwrap();
+
+        assert_eq!(
+            preview.title,
+            Some("Rust Language (@rustlang) on X".to_string())
+        );
+        assert_eq!(
+            preview.description,
+            Some("Rust 1.83.0 is out! This release extends const capabilities and stabilizes a handful of new APIs.".to_string())
+        );
+        assert_eq!(
+            preview.image_url.map(|u| u.to_string()),
+            Some("https://pbs.twimg.com/media/GfJd3kVXoAAqN2Z.jpg:large".to_string())
+        );
+    }
+
+    #[test]
+    fn cleans_up_boilerplate_titles() {
+        assert_eq!(
+            clean_title("Rust Language on X: \"Rust 1.83.0 is out!\" / X"),
+            Some("Rust Language on X: \"Rust 1.83.0 is out!\"".to_string())
+        );
+        assert_eq!(
+            clean_title("Ferris (@ferris) / Twitter"),
+            Some("Ferris (@ferris)".to_string())
+        );
+        assert_eq!(clean_title("X. It’s what’s happening / X"), None);
+        assert_eq!(clean_title("Twitter. It's what's happening"), None);
+        assert_eq!(clean_title("X"), None);
+    }
+
+    #[test]
+    fn fits_twitter_and_x_hosts_only() {
+        let fits = |url: &str| TwitterProfile::fits(&Url::parse(url).unwrap());
+
+        assert!(fits("https://twitter.com/rustlang"));
+        assert!(fits("https://mobile.twitter.com/rustlang/status/1"));
+        assert!(fits("https://x.com/rustlang/status/1"));
+        assert!(!fits("https://x.company.com/rustlang"));
+        assert!(!fits("https://nottwitter.com/rustlang"));
+    }
+}
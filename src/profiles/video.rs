@@ -0,0 +1,123 @@
+use scraper::Html;
+use url::Url;
+
+#[cfg(feature = "json")]
+use serde_json::Value;
+
+use crate::profiles::ProfileExt;
+use crate::LinkPreview;
+
+#[cfg(feature = "json")]
+use crate::media::parse_iso8601_duration;
+#[cfg(feature = "json")]
+use crate::providers::jsonld::{find_jsonld_entity, jsonld_str, jsonld_url};
+
+#[cfg(feature = "json")]
+const VIDEO_OBJECT_TYPE: &str = "VideoObject";
+
+/// Pages from any host declaring a `VideoObject` JSON-LD entity, such as the
+/// ones from smaller video platforms and news sites embedding their own
+/// player.
+///
+/// The video's `embedUrl` (or its `contentUrl` when there's no player) is
+/// used as `video_url`, its thumbnail as `image_url` and its ISO 8601
+/// duration as `duration`. As the entity is read from JSON-LD, the profile
+/// only fits pages with the `json` feature.
+pub struct VideoProfile {}
+
+impl ProfileExt for VideoProfile {
+    fn extract(html: &Html) -> Option<LinkPreview> {
+        let link_preview = LinkPreview::from(html);
+
+        #[cfg(feature = "json")]
+        let link_preview = match find_jsonld_entity(html, VIDEO_OBJECT_TYPE) {
+            Some(video) => complete_with_video(link_preview, &video),
+            None => link_preview,
+        };
+
+        Some(link_preview)
+    }
+
+    fn fits(_url: &Url) -> bool {
+        false
+    }
+
+    #[cfg(feature = "json")]
+    fn fits_content(html: &Html) -> bool {
+        find_jsonld_entity(html, VIDEO_OBJECT_TYPE).is_some()
+    }
+}
+
+/// Completes the provided `LinkPreview` with the `VideoObject` JSON-LD entity
+#[cfg(feature = "json")]
+fn complete_with_video(mut link_preview: LinkPreview, video: &Value) -> LinkPreview {
+    if link_preview.title.is_none() {
+        link_preview.title = jsonld_str(video, "name");
+    }
+
+    if link_preview.description.is_none() {
+        link_preview.description = jsonld_str(video, "description");
+    }
+
+    if let Some(video_url) = jsonld_str(video, "embedUrl")
+        .or_else(|| jsonld_str(video, "contentUrl"))
+        .and_then(|video_url| Url::parse(&video_url).ok())
+    {
+        link_preview.video_url = Some(video_url);
+    }
+
+    if let Some(thumbnail) = video
+        .get("thumbnailUrl")
+        .or_else(|| video.get("thumbnail"))
+        .and_then(jsonld_url)
+        .and_then(|image_url| Url::parse(&image_url).ok())
+    {
+        link_preview.image_url = Some(thumbnail);
+    }
+
+    if let Some(duration) =
+        jsonld_str(video, "duration").and_then(|duration| parse_iso8601_duration(&duration))
+    {
+        link_preview.duration = Some(duration);
+    }
+
+    link_preview
+}
+
+#[cfg(all(test, feature = "json"))]
+mod tests {
+    use crate::html_from_bytes;
+    use crate::tests::{OG_COMPLIANT_HTML, VIDEO_OBJECT_HTML};
+
+    use super::*;
+
+    #[test]
+    fn test_video_profile() {
+        let html = html_from_bytes(VIDEO_OBJECT_HTML).unwrap();
+
+        assert!(VideoProfile::fits_content(&html));
+
+        let preview = VideoProfile::extract(&html).unwrap();
+
+        assert_eq!(
+            preview.title,
+            Some("Restoring a 1960s Letterpress".to_string())
+        );
+        assert_eq!(
+            preview.video_url.map(|u| u.to_string()),
+            Some("https://clips.example.org/embed/letterpress-restoration".to_string())
+        );
+        assert_eq!(
+            preview.image_url.map(|u| u.to_string()),
+            Some("https://clips.example.org/thumbs/letterpress-restoration-1280.jpg".to_string())
+        );
+        assert_eq!(preview.duration, Some(750));
+    }
+
+    #[test]
+    fn does_not_fit_pages_without_video_object() {
+        let html = html_from_bytes(OG_COMPLIANT_HTML).unwrap();
+
+        assert!(!VideoProfile::fits_content(&html));
+    }
+}
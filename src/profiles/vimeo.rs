@@ -0,0 +1,172 @@
+use scraper::Html;
+use url::Url;
+
+use crate::profiles::ProfileExt;
+use crate::providers::oembed::find_oembed_endpoint;
+use crate::providers::og::{find_og_tag, OpenGraphTag};
+use crate::LinkPreview;
+
+const VIMEO_IMAGE_STORAGE_DOMAIN: &str = "https://i.vimeocdn.com";
+
+/// Domain of the CDN serving Vimeo thumbnails, through hosts such as
+/// `i.vimeocdn.com` or `secure-b.vimeocdn.com`
+const VIMEO_CDN_DOMAIN: &str = "vimeocdn.com";
+
+/// Path of the endpoint drawing a play button over the thumbnail in `src0`
+const OVERLAY_FILTER_PATH: &str = "/filter/overlay";
+
+/// Hosts serving Vimeo videos and their embeddable player
+const VIMEO_HOSTS: [&str; 3] = ["vimeo.com", "www.vimeo.com", "player.vimeo.com"];
+
+/// Site name Vimeo declares through `og:site_name`
+const VIMEO_SITE_NAME: &str = "Vimeo";
+
+/// Vimeo videos, either from the site (`vimeo.com`) or the embeddable player
+/// (`player.vimeo.com`).
+///
+/// The thumbnail is unwrapped from the play button overlay Vimeo draws over
+/// it for crawlers and served from `i.vimeocdn.com` without resizing
+/// parameters. When the page declares no image, fetching functions fall back
+/// to the thumbnail of its oEmbed response.
+pub struct VimeoProfile {}
+
+impl ProfileExt for VimeoProfile {
+    fn extract(html: &Html) -> Option<LinkPreview> {
+        let mut link_preview = LinkPreview::from(html);
+
+        if let Some(image_url) = link_preview.image_url.as_ref() {
+            link_preview.image_url = normalize_thumbnail_url(image_url);
+        }
+
+        Some(link_preview)
+    }
+
+    fn fits(url: &Url) -> bool {
+        url.host_str().is_some_and(|host| {
+            VIMEO_HOSTS
+                .iter()
+                .any(|vimeo_host| host.eq_ignore_ascii_case(vimeo_host))
+        })
+    }
+
+    fn fits_content(html: &Html) -> bool {
+        find_og_tag(html, OpenGraphTag::SiteName).as_deref() == Some(VIMEO_SITE_NAME)
+    }
+
+    fn oembed_endpoint(html: &Html, preview: &LinkPreview) -> Option<Url> {
+        if preview.image_url.is_some() {
+            return None;
+        }
+
+        find_oembed_endpoint(html)
+    }
+}
+
+/// Unwraps the thumbnail from Vimeo's overlay filter (e.g.
+/// `https://i.vimeocdn.com/filter/overlay?src0=...&src1=...`) and serves it
+/// from `i.vimeocdn.com`, dropping the query. URLs outside Vimeo's CDN are
+/// kept as is.
+fn normalize_thumbnail_url(image_url: &Url) -> Option<Url> {
+    let on_cdn = |url: &Url| {
+        url.host_str().is_some_and(|host| {
+            host == VIMEO_CDN_DOMAIN || host.ends_with(&format!(".{VIMEO_CDN_DOMAIN}"))
+        })
+    };
+
+    if !on_cdn(image_url) {
+        return Some(image_url.clone());
+    }
+
+    let mut thumbnail_url = image_url.clone();
+
+    if image_url.path() == OVERLAY_FILTER_PATH {
+        if let Some(source) = image_url
+            .query_pairs()
+            .find(|(key, _)| key == "src0")
+            .and_then(|(_, source)| Url::parse(&source).ok())
+            .filter(on_cdn)
+        {
+            thumbnail_url = source;
+        }
+    }
+
+    let mut url = Url::parse(VIMEO_IMAGE_STORAGE_DOMAIN).ok()?;
+    url.set_path(thumbnail_url.path());
+
+    Some(url)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html_from_bytes;
+    use crate::tests::{VIMEO_VIDEO_HTML, YOUTUBE_VIDEO_HTML};
+
+    use super::*;
+
+    #[test]
+    fn test_vimeo_profile() {
+        let html = html_from_bytes(VIMEO_VIDEO_HTML).unwrap();
+        let url = Url::parse("https://vimeo.com/76979871").unwrap();
+
+        assert!(VimeoProfile::fits(&url));
+        assert!(VimeoProfile::fits_content(&html));
+
+        let preview = VimeoProfile::extract(&html).unwrap();
+
+        assert_eq!(
+            preview.title,
+            Some("Coastal Light — A Short Film".to_string())
+        );
+        assert_eq!(
+            preview.image_url.map(|u| u.to_string()),
+            Some("https://i.vimeocdn.com/video/452001751-8216e7bba3e5a0c3f5e0b8d4e5c1f2a9d0b3e6c7f8a9b0c1d2e3f4a5b6c7d8e9-d_1280x720".to_string())
+        );
+        assert!(!VimeoProfile::fits_content(
+            &html_from_bytes(YOUTUBE_VIDEO_HTML).unwrap()
+        ));
+    }
+
+    #[test]
+    fn normalizes_thumbnail_urls() {
+        let normalize = |url: &str| {
+            normalize_thumbnail_url(&Url::parse(url).unwrap())
+                .unwrap()
+                .to_string()
+        };
+
+        assert_eq!(
+            normalize("http://secure-b.vimeocdn.com/ts/452/001/452001751_640.jpg?mw=640"),
+            "https://i.vimeocdn.com/ts/452/001/452001751_640.jpg"
+        );
+        assert_eq!(
+            normalize("https://example.com/thumbnails/coastal-light.jpg?v=2"),
+            "https://example.com/thumbnails/coastal-light.jpg?v=2"
+        );
+    }
+
+    #[test]
+    fn fits_vimeo_and_player_hosts_only() {
+        let fits = |url: &str| VimeoProfile::fits(&Url::parse(url).unwrap());
+
+        assert!(fits("https://player.vimeo.com/video/76979871"));
+        assert!(fits("https://www.vimeo.com/76979871"));
+        assert!(!fits("https://notvimeo.com/76979871"));
+    }
+
+    #[test]
+    fn falls_back_to_oembed_when_declaring_no_image() {
+        let html = html_from_bytes(VIMEO_VIDEO_HTML).unwrap();
+        let mut preview = VimeoProfile::extract(&html).unwrap();
+
+        assert!(VimeoProfile::oembed_endpoint(&html, &preview).is_none());
+
+        preview.image_url = None;
+
+        assert_eq!(
+            VimeoProfile::oembed_endpoint(&html, &preview)
+                .unwrap()
+                .as_str(),
+            "https://vimeo.com/api/oembed.json?url=https%3A%2F%2Fvimeo.com%2F76979871"
+        );
+    }
+}
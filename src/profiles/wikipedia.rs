@@ -0,0 +1,140 @@
+use scraper::Html;
+use url::Url;
+
+use crate::profiles::ProfileExt;
+use crate::LinkPreview;
+
+const WIKIPEDIA_DOMAIN: &str = "wikipedia.org";
+
+/// Suffix of the hosts serving the mobile version of each language edition,
+/// e.g. `es.m.wikipedia.org`
+const MOBILE_DOMAIN_SUFFIX: &str = ".m.wikipedia.org";
+
+/// Separators between the article name and the site name in page titles
+const TITLE_SEPARATORS: [&str; 3] = [" - ", " – ", " — "];
+
+/// Localized names of Wikipedia found in the title suffix of each language
+/// edition, e.g. `Tokio - Wikipedia, la enciclopedia libre`
+const LOCALIZED_NAMES: [&str; 13] = [
+    "wikipedia",
+    "wikipédia",
+    "wikipedija",
+    "википедия",
+    "вікіпедія",
+    "βικιπαίδεια",
+    "ויקיפדיה",
+    "ويكيبيديا",
+    "विकिपीडिया",
+    "维基百科",
+    "維基百科",
+    "위키백과",
+    "ウィキペディア",
+];
+
+/// Wikipedia articles from any language edition, either from the desktop
+/// (`en.wikipedia.org`) or the mobile (`en.m.wikipedia.org`) site.
+///
+/// The site name suffix (e.g. ` - Wikipedia` or the localized
+/// ` - Wikipedia, la enciclopedia libre`) is dropped from the `title`, and
+/// mobile hosts are replaced by their desktop counterpart in the `domain`
+/// and `canonical_url`.
+pub struct WikipediaProfile {}
+
+impl ProfileExt for WikipediaProfile {
+    fn extract(html: &Html) -> Option<LinkPreview> {
+        let mut link_preview = LinkPreview::from(html);
+
+        if let Some(title) = link_preview.title.as_mut() {
+            *title = strip_site_name(title).to_string();
+        }
+
+        if let Some(domain) = link_preview.domain.as_mut() {
+            if let Some(desktop) = desktop_host(domain) {
+                *domain = desktop;
+            }
+        }
+
+        if let Some(canonical_url) = link_preview.canonical_url.as_mut() {
+            if let Some(desktop) = canonical_url.host_str().and_then(desktop_host) {
+                let _ = canonical_url.set_host(Some(&desktop));
+            }
+        }
+
+        Some(link_preview)
+    }
+
+    fn fits(url: &Url) -> bool {
+        url.host_str().is_some_and(|host| {
+            host == WIKIPEDIA_DOMAIN || host.ends_with(&format!(".{WIKIPEDIA_DOMAIN}"))
+        })
+    }
+}
+
+/// Removes the trailing site name from an article title, keeping the title
+/// as is when it doesn't end with one
+fn strip_site_name(title: &str) -> &str {
+    TITLE_SEPARATORS
+        .iter()
+        .filter_map(|separator| title.rsplit_once(separator))
+        .find(|(_, suffix)| {
+            let suffix = suffix.to_lowercase();
+
+            LOCALIZED_NAMES.iter().any(|name| suffix.contains(name))
+        })
+        .map_or(title, |(article, _)| article.trim())
+}
+
+/// Maps a mobile host (e.g. `es.m.wikipedia.org`) to its desktop counterpart
+/// (e.g. `es.wikipedia.org`), `None` when the host isn't a mobile one
+fn desktop_host(host: &str) -> Option<String> {
+    let language = host.strip_suffix(MOBILE_DOMAIN_SUFFIX)?;
+
+    Some(format!("{language}.{WIKIPEDIA_DOMAIN}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html_from_bytes;
+    use crate::tests::WIKIPEDIA_MOBILE_HTML;
+
+    use super::*;
+
+    #[test]
+    fn test_wikipedia_mobile_profile() {
+        let html = html_from_bytes(WIKIPEDIA_MOBILE_HTML).unwrap();
+        let url = Url::parse("https://es.m.wikipedia.org/wiki/Tokio").unwrap();
+
+        assert!(WikipediaProfile::fits(&url));
+
+        let preview = WikipediaProfile::extract(&html).unwrap();
+
+        assert_eq!(preview.title, Some("Tokio".to_string()));
+        assert_eq!(preview.domain, Some("es.wikipedia.org".to_string()));
+        assert_eq!(
+            preview.canonical_url.map(|u| u.to_string()),
+            Some("https://es.wikipedia.org/wiki/Tokio".to_string())
+        );
+    }
+
+    #[test]
+    fn strips_localized_site_names() {
+        assert_eq!(strip_site_name("Tokyo - Wikipedia"), "Tokyo");
+        assert_eq!(strip_site_name("Токио — Википедия"), "Токио");
+        assert_eq!(strip_site_name("東京 - 维基百科，自由的百科全书"), "東京");
+        assert_eq!(
+            strip_site_name("Rock - Paper - Scissors - Wikipedia"),
+            "Rock - Paper - Scissors"
+        );
+        assert_eq!(strip_site_name("Rock - Paper"), "Rock - Paper");
+    }
+
+    #[test]
+    fn fits_desktop_and_mobile_hosts_only() {
+        let fits = |url: &str| WikipediaProfile::fits(&Url::parse(url).unwrap());
+
+        assert!(fits("https://en.wikipedia.org/wiki/Rust"));
+        assert!(fits("https://de.m.wikipedia.org/wiki/Rust"));
+        assert!(!fits("https://notwikipedia.org/wiki/Rust"));
+        assert!(!fits("https://en.wikipedia.org.example.com/wiki/Rust"));
+    }
+}
@@ -0,0 +1,115 @@
+use scraper::Html;
+use url::Url;
+
+use crate::html::{find_application_name, find_link, find_meta_tag};
+use crate::profiles::ProfileExt;
+use crate::providers::og::{find_og_tag, OpenGraphTag};
+use crate::providers::twitter::find_twitter_labels;
+use crate::LinkPreview;
+
+/// `rel` of the `<link>` element pointing to the WordPress REST API
+const WORDPRESS_API_LINK_REL: &str = "https://api.w.org/";
+
+/// Label Yoast SEO uses for the author in the `twitter:label*` meta tags
+const YOAST_AUTHOR_LABEL: &str = "Written by";
+
+/// Pages from WordPress sites, detected through the `<link>` element pointing
+/// to the WordPress REST API.
+///
+/// The `article:*` OpenGraph tags and the meta tags from the Yoast SEO plugin
+/// are used to find the `author`, and `article:publisher` is used as the
+/// `site_name` when neither `og:site_name` nor `application-name` are
+/// declared. Tags holding a URL (such as a Facebook profile) instead of a
+/// name are skipped.
+pub struct WordPressProfile {}
+
+impl ProfileExt for WordPressProfile {
+    fn extract(html: &Html) -> Option<LinkPreview> {
+        let mut link_preview = LinkPreview::from(html);
+
+        if let Some(author) = find_name_tag(html, "article:author").or_else(|| yoast_author(html)) {
+            link_preview.author = Some(author);
+        }
+
+        let declares_site_name = find_og_tag(html, OpenGraphTag::SiteName)
+            .or_else(|| find_application_name(html))
+            .is_some();
+
+        if !declares_site_name {
+            if let Some(publisher) = find_name_tag(html, "article:publisher") {
+                link_preview.site_name = Some(publisher);
+            }
+        }
+
+        Some(link_preview)
+    }
+
+    fn fits(_url: &Url) -> bool {
+        false
+    }
+
+    fn fits_content(html: &Html) -> bool {
+        find_link(html, WORDPRESS_API_LINK_REL).is_some()
+    }
+}
+
+/// Retrieves the `property` meta tag unless it holds a URL rather than a name
+fn find_name_tag(html: &Html, property: &str) -> Option<String> {
+    find_meta_tag(html, property).filter(|value| Url::parse(value).is_err())
+}
+
+/// Retrieves the author Yoast SEO declares through the `twitter:label*` and
+/// `twitter:data*` meta tag pairs
+fn yoast_author(html: &Html) -> Option<String> {
+    find_twitter_labels(html)
+        .into_iter()
+        .find(|(label, _)| label == YOAST_AUTHOR_LABEL)
+        .map(|(_, author)| author)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html_from_bytes;
+    use crate::tests::{OG_COMPLIANT_HTML, WORDPRESS_POST_HTML};
+
+    use super::*;
+
+    #[test]
+    fn test_wordpress_profile() {
+        let html = html_from_bytes(WORDPRESS_POST_HTML).unwrap();
+
+        assert!(WordPressProfile::fits_content(&html));
+
+        let preview = WordPressProfile::extract(&html).unwrap();
+
+        assert_eq!(
+            preview.title,
+            Some("Why your sourdough is flat (and how to fix it)".to_string())
+        );
+        assert_eq!(preview.author, Some("Maria Alves".to_string()));
+        assert_eq!(preview.site_name, Some("The Daily Crumb".to_string()));
+        assert_eq!(preview.domain, Some("dailycrumb.example.com".to_string()));
+    }
+
+    #[test]
+    fn uses_article_tags_when_named() {
+        let html = Html::parse_document(
+            r#"<html><head>
+                <link rel="https://api.w.org/" href="https://example.com/wp-json/" />
+                <meta property="article:author" content="Jo Park" />
+                <meta property="article:publisher" content="Example Weekly" />
+            </head></html>"#,
+        );
+        let preview = WordPressProfile::extract(&html).unwrap();
+
+        assert_eq!(preview.author, Some("Jo Park".to_string()));
+        assert_eq!(preview.site_name, Some("Example Weekly".to_string()));
+    }
+
+    #[test]
+    fn does_not_fit_other_sites() {
+        let html = html_from_bytes(OG_COMPLIANT_HTML).unwrap();
+
+        assert!(!WordPressProfile::fits_content(&html));
+    }
+}
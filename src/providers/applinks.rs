@@ -0,0 +1,70 @@
+//! App Links bindings
+//!
+//! App Links are `al:*` meta tags declaring how to open the content of a web
+//! page in a native mobile application (deep-linking), along with the web URL
+//! to fall back to.
+//!
+//! # References
+//! - [App Links](https://developers.facebook.com/docs/applinks/metadata-reference)
+use scraper::Html;
+use url::Url;
+
+use crate::html::find_meta_tag;
+
+/// Deep-link URLs declared through the App Links (`al:*`) meta tags
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AppLinks {
+    /// URL opening the content in the iOS app (`al:ios:url`), falling back
+    /// to the iPhone specific one (`al:iphone:url`)
+    pub ios: Option<Url>,
+    /// URL opening the content in the Android app (`al:android:url`)
+    pub android: Option<Url>,
+    /// URL to open when no app is available (`al:web:url`)
+    pub web: Option<Url>,
+}
+
+/// Retrieves the App Links declared by the provided `Html` instance. URLs
+/// failing to parse are treated as missing.
+pub fn find_app_links(html: &Html) -> AppLinks {
+    let find_url = |property: &str| {
+        find_meta_tag(html, property).and_then(|value| Url::parse(value.trim()).ok())
+    };
+
+    AppLinks {
+        ios: find_url("al:ios:url").or_else(|| find_url("al:iphone:url")),
+        android: find_url("al:android:url"),
+        web: find_url("al:web:url"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html_from_bytes;
+    use crate::tests::{APP_LINKS_HTML, OG_COMPLIANT_HTML};
+
+    use super::{find_app_links, AppLinks};
+
+    #[test]
+    fn finds_app_links() {
+        let html = html_from_bytes(APP_LINKS_HTML).unwrap();
+        let app_links = find_app_links(&html);
+
+        assert_eq!(
+            app_links.ios.unwrap().as_str(),
+            "tablebook://restaurants/casa-verde"
+        );
+        assert_eq!(
+            app_links.android.unwrap().as_str(),
+            "tablebook://restaurants/casa-verde"
+        );
+        // `al:web:url` holds an invalid URL
+        assert!(app_links.web.is_none());
+    }
+
+    #[test]
+    fn finds_no_app_links() {
+        let html = html_from_bytes(OG_COMPLIANT_HTML).unwrap();
+
+        assert_eq!(find_app_links(&html), AppLinks::default());
+    }
+}
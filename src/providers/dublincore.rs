@@ -0,0 +1,108 @@
+//! Dublin Core bindings
+//!
+//! Dublin Core elements are declared through `DC.*` (or `DCTERMS.*`) meta
+//! tags, mostly by libraries, universities and government sites.
+//!
+//! # References
+//! - [DCMI Metadata Terms](https://www.dublincore.org/specifications/dublin-core/dcmi-terms/)
+//! - [Expressing Dublin Core in HTML](https://www.dublincore.org/specifications/dublin-core/dc-html/)
+use std::sync::LazyLock;
+
+use scraper::{Html, Selector};
+
+use crate::html::meta_content;
+use crate::providers::meta::MetaProvider;
+
+/// Dublin Core elements describing the page
+pub enum DublinCoreTag {
+    /// `DC.title`
+    Title,
+    /// `DC.description`
+    Description,
+    /// `DC.creator`, the person or organization who authored the page
+    Creator,
+}
+
+impl DublinCoreTag {
+    fn str(&self) -> &str {
+        match self {
+            DublinCoreTag::Title => "title",
+            DublinCoreTag::Description => "description",
+            DublinCoreTag::Creator => "creator",
+        }
+    }
+}
+
+/// Dublin Core metadata provider, reading `<meta name="DC.{key}">` and
+/// `<meta name="DCTERMS.{key}">` tags compared case-insensitively.
+///
+/// The `author` key is read from the `creator` element.
+pub struct DublinCore;
+
+impl MetaProvider for DublinCore {
+    fn lookup(&self, html: &Html, key: &str) -> Option<String> {
+        let element = match key {
+            "author" => "creator",
+            key => key,
+        };
+        let names = [format!("dc.{element}"), format!("dcterms.{element}")];
+        static SELECTOR: LazyLock<Selector> =
+            LazyLock::new(|| Selector::parse("meta[name]").unwrap());
+
+        html.select(&SELECTOR)
+            .find(|element| {
+                element.value().attr("name").is_some_and(|name| {
+                    names
+                        .iter()
+                        .any(|candidate| name.eq_ignore_ascii_case(candidate))
+                })
+            })
+            .and_then(|element| meta_content(element.value()))
+            .map(|value| value.to_string())
+    }
+}
+
+/// Finds the Dublin Core element specified in the provided `Html` instance,
+/// trimmed and skipped when blank
+pub fn find_dc_tag(html: &Html, tag: DublinCoreTag) -> Option<String> {
+    DublinCore
+        .lookup(html, tag.str())
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html_from_bytes;
+    use crate::providers::meta::MetaProvider;
+    use crate::tests::DUBLIN_CORE_HTML;
+
+    use super::{find_dc_tag, DublinCore, DublinCoreTag};
+
+    #[test]
+    fn retrieves_dublin_core_elements() {
+        let html = html_from_bytes(DUBLIN_CORE_HTML).unwrap();
+
+        assert_eq!(
+            DublinCore.lookup(&html, "description").unwrap(),
+            "Hive counts, honey yields and forage maps from 42 rooftop apiaries."
+        );
+        assert_eq!(DublinCore.lookup(&html, "language").unwrap(), "en");
+        assert_eq!(DublinCore.lookup(&html, "date").unwrap(), "2024-03-01");
+        assert!(DublinCore.lookup(&html, "image").is_none());
+    }
+
+    #[test]
+    fn finds_dublin_core_tags() {
+        let html = html_from_bytes(DUBLIN_CORE_HTML).unwrap();
+
+        assert_eq!(
+            find_dc_tag(&html, DublinCoreTag::Title).unwrap(),
+            "Annual Report on Urban Beekeeping"
+        );
+        assert_eq!(
+            find_dc_tag(&html, DublinCoreTag::Creator).unwrap(),
+            "Greenfield Apiary Collective"
+        );
+    }
+}
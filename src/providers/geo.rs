@@ -0,0 +1,106 @@
+//! Geographic location bindings
+//!
+//! Locations may be declared through OpenGraph's `place:location:*` tags or
+//! the `geo.position` and `ICBM` meta tags.
+//!
+//! # References
+//! - [Open Graph Place](https://developers.facebook.com/docs/sharing/opengraph/object-properties#place)
+//! - [Geo Tag](https://en.wikipedia.org/wiki/Geotagging#HTML_pages)
+use scraper::Html;
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::html::{find_meta_by_name, find_meta_tag};
+
+/// Coordinates of a location, in decimal degrees
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct Geo {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+impl Geo {
+    /// Creates a `Geo` if the coordinates are within valid ranges
+    pub fn new(latitude: f64, longitude: f64) -> Option<Self> {
+        let valid = (-90.0..=90.0).contains(&latitude) && (-180.0..=180.0).contains(&longitude);
+
+        valid.then_some(Geo {
+            latitude,
+            longitude,
+        })
+    }
+
+    /// Parses a pair of coordinates separated by either a comma or a
+    /// semicolon, e.g. `38.7139,-9.1452` or `38.7139; -9.1452`
+    pub fn parse(value: &str) -> Option<Self> {
+        let (latitude, longitude) = value.split_once([',', ';'])?;
+
+        Geo::new(
+            latitude.trim().parse().ok()?,
+            longitude.trim().parse().ok()?,
+        )
+    }
+}
+
+/// Attempts to find the location the document refers to in the following
+/// order:
+///
+/// - OpenGraph's `place:location:latitude` and `place:location:longitude`
+/// - The `geo.position` meta tag
+/// - The `ICBM` meta tag
+pub fn find_geo(html: &Html) -> Option<Geo> {
+    let latitude = find_meta_tag(html, "place:location:latitude");
+    let longitude = find_meta_tag(html, "place:location:longitude");
+
+    if let (Some(latitude), Some(longitude)) = (latitude, longitude) {
+        if let Some(geo) = Geo::parse(&format!("{latitude},{longitude}")) {
+            return Some(geo);
+        }
+    }
+
+    ["geo.position", "ICBM"]
+        .iter()
+        .find_map(|name| find_meta_by_name(html, name).and_then(|value| Geo::parse(&value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use scraper::Html;
+
+    use super::{find_geo, Geo};
+
+    #[test]
+    fn finds_geo_from_place_tags() {
+        let html = Html::parse_document(
+            r#"<html><head>
+                <meta property="place:location:latitude" content="48.8584" />
+                <meta property="place:location:longitude" content="2.2945" />
+                <meta name="geo.position" content="1;1" />
+            </head></html>"#,
+        );
+
+        assert_eq!(find_geo(&html), Geo::new(48.8584, 2.2945));
+    }
+
+    #[test]
+    fn finds_geo_from_geo_position_and_icbm() {
+        let geo_position =
+            Html::parse_document(r#"<meta name="geo.position" content="-33.8568;151.2153">"#);
+        let icbm = Html::parse_document(r#"<meta name="ICBM" content="-33.8568, 151.2153">"#);
+
+        assert_eq!(find_geo(&geo_position), Geo::new(-33.8568, 151.2153));
+        assert_eq!(find_geo(&icbm), Geo::new(-33.8568, 151.2153));
+    }
+
+    #[test]
+    fn rejects_out_of_range_coordinates() {
+        assert!(Geo::parse("91.0,10.0").is_none());
+        assert!(Geo::parse("10.0;-181").is_none());
+        assert!(Geo::parse("north").is_none());
+    }
+}
@@ -0,0 +1,305 @@
+//! JSON-LD bindings
+//!
+//! Linked Data embedded in `<script type="application/ld+json">` elements,
+//! usually following the Schema.org vocabulary. Parsing them requires the
+//! `json` feature, without which no JSON-LD is found.
+//!
+//! # References
+//! - [JSON-LD](https://json-ld.org)
+//! - [Schema.org](https://schema.org)
+#[cfg(feature = "json")]
+use std::sync::LazyLock;
+
+use scraper::Html;
+#[cfg(feature = "json")]
+use scraper::Selector;
+#[cfg(feature = "json")]
+use serde_json::Value;
+
+/// `@type`s of the entities describing the page itself, read by
+/// `find_jsonld_tag`, from the most to the least preferred
+#[cfg(feature = "json")]
+const PREVIEW_TYPES: [&str; 5] = [
+    "NewsArticle",
+    "BlogPosting",
+    "Article",
+    "Product",
+    "VideoObject",
+];
+
+/// JSON-LD properties describing the page
+pub enum JsonLdField {
+    /// The entity's `headline`, or its `name` when there's none
+    Title,
+    Description,
+    /// The entity's `image`, or its `thumbnailUrl` when there's none
+    Image,
+    Url,
+    /// The name of the entity's `author`, either a `Person` or an
+    /// `Organization`
+    Author,
+    DatePublished,
+    DateModified,
+}
+
+#[cfg(feature = "json")]
+impl JsonLdField {
+    fn keys(&self) -> &[&str] {
+        match self {
+            JsonLdField::Title => &["headline", "name"],
+            JsonLdField::Description => &["description"],
+            JsonLdField::Image => &["image", "thumbnailUrl"],
+            JsonLdField::Url => &["url"],
+            JsonLdField::Author => &["author"],
+            JsonLdField::DatePublished => &["datePublished"],
+            JsonLdField::DateModified => &["dateModified"],
+        }
+    }
+}
+
+/// Finds the value of the provided field in the JSON-LD entities describing
+/// the page, that is, a `NewsArticle`, `BlogPosting`, `Article`, `Product`
+/// or `VideoObject`. Entities are preferred in that order of types regardless
+/// of where they're declared, so an article wins over a video embedded in
+/// it, and the first entity of the same type declaring the field is used.
+///
+/// URLs are retrieved as declared, either from a string, an `ImageObject` or
+/// the first of a list of them, and so are names from a string, a `Person`
+/// or the first of a list of them.
+#[cfg(feature = "json")]
+pub fn find_jsonld_tag(html: &Html, key: JsonLdField) -> Option<String> {
+    let entities = find_jsonld_entities(html);
+    let mut entities: Vec<(usize, &Value)> = entities
+        .iter()
+        .filter_map(|entity| {
+            PREVIEW_TYPES
+                .iter()
+                .position(|kind| has_type(entity, kind))
+                .map(|priority| (priority, entity))
+        })
+        .collect();
+
+    entities.sort_by_key(|(priority, _)| *priority);
+
+    entities.into_iter().find_map(|(_, entity)| {
+        key.keys().iter().find_map(|property| {
+            let value = entity.get(property)?;
+            let value = match key {
+                JsonLdField::Image | JsonLdField::Url => jsonld_url(value),
+                JsonLdField::Title
+                | JsonLdField::Description
+                | JsonLdField::DatePublished
+                | JsonLdField::DateModified => value.as_str().map(|value| value.to_string()),
+                JsonLdField::Author => jsonld_name(value),
+            }?;
+            let value = value.trim();
+
+            (!value.is_empty()).then(|| value.to_string())
+        })
+    })
+}
+
+/// Finds the value of the provided field in the JSON-LD entities describing
+/// the page, always `None` as JSON-LD isn't parsed without the `json` feature
+#[cfg(not(feature = "json"))]
+pub fn find_jsonld_tag(_html: &Html, _key: JsonLdField) -> Option<String> {
+    None
+}
+
+/// Parses every JSON-LD script block in the provided `Html` instance and
+/// retrieves its top level entities. Blocks holding invalid JSON are skipped.
+///
+/// Entities wrapped in a list or in a `@graph` are flattened, so each of them
+/// is retrieved on its own.
+#[cfg(feature = "json")]
+pub fn find_jsonld_entities(html: &Html) -> Vec<Value> {
+    static SELECTOR: LazyLock<Selector> =
+        LazyLock::new(|| Selector::parse("script[type=\"application/ld+json\"]").unwrap());
+    let mut entities = Vec::new();
+
+    for element in html.select(&SELECTOR) {
+        let text: String = element.text().collect();
+
+        if let Ok(value) = serde_json::from_str::<Value>(&text) {
+            flatten_entities(value, &mut entities);
+        }
+    }
+
+    entities
+}
+
+/// Pushes the entities held by `value` into `entities`, unwrapping lists and
+/// `@graph` containers
+#[cfg(feature = "json")]
+fn flatten_entities(value: Value, entities: &mut Vec<Value>) {
+    match value {
+        Value::Array(values) => {
+            for value in values {
+                flatten_entities(value, entities);
+            }
+        }
+        Value::Object(mut object) if object.contains_key("@graph") => {
+            if let Some(graph) = object.remove("@graph") {
+                flatten_entities(graph, entities);
+            }
+        }
+        value => entities.push(value),
+    }
+}
+
+/// Finds the first JSON-LD entity of the provided `@type`
+#[cfg(feature = "json")]
+pub fn find_jsonld_entity(html: &Html, kind: &str) -> Option<Value> {
+    find_jsonld_entities(html)
+        .into_iter()
+        .find(|entity| has_type(entity, kind))
+}
+
+/// Checks whether the entity's `@type`, either a single type or a list of
+/// types, includes `kind`
+#[cfg(feature = "json")]
+pub fn has_type(entity: &Value, kind: &str) -> bool {
+    match entity.get("@type") {
+        Some(Value::String(value)) => value == kind,
+        Some(Value::Array(values)) => values.iter().any(|value| value.as_str() == Some(kind)),
+        _ => false,
+    }
+}
+
+/// Retrieves a URL from a JSON-LD value, which may either be a string, an
+/// object with an `url` or `contentUrl` property (e.g. an `ImageObject`) or a
+/// list of any of them, in which case the first URL is used
+#[cfg(feature = "json")]
+pub fn jsonld_url(value: &Value) -> Option<String> {
+    match value {
+        Value::String(url) => Some(url.to_string()),
+        Value::Array(values) => values.iter().find_map(jsonld_url),
+        Value::Object(object) => object
+            .get("url")
+            .or_else(|| object.get("contentUrl"))
+            .and_then(jsonld_url),
+        _ => None,
+    }
+}
+
+/// Retrieves a name from a JSON-LD value, which may either be a string, an
+/// object with a `name` property (e.g. a `Person`) or a list of any of them,
+/// in which case the first name is used
+#[cfg(feature = "json")]
+pub fn jsonld_name(value: &Value) -> Option<String> {
+    match value {
+        Value::String(name) => Some(name.to_string()),
+        Value::Array(values) => values.iter().find_map(jsonld_name),
+        Value::Object(object) => object.get("name").and_then(jsonld_name),
+        _ => None,
+    }
+}
+
+/// Retrieves the string value of the property `key` of the provided entity
+#[cfg(feature = "json")]
+pub fn jsonld_str(entity: &Value, key: &str) -> Option<String> {
+    entity
+        .get(key)
+        .and_then(Value::as_str)
+        .map(|value| value.to_string())
+}
+
+#[cfg(all(test, feature = "json"))]
+mod tests {
+    use crate::html_from_bytes;
+    use crate::tests::{
+        JSONLD_ARTICLE_HTML, JSONLD_COMPETING_TYPES_HTML, JSONLD_GRAPH_HTML, PODCAST_EPISODE_HTML,
+    };
+
+    use super::{
+        find_jsonld_entities, find_jsonld_entity, find_jsonld_tag, jsonld_name, jsonld_str,
+        jsonld_url, JsonLdField,
+    };
+
+    #[test]
+    fn retrieves_entity_by_type() {
+        let html = html_from_bytes(PODCAST_EPISODE_HTML).unwrap();
+        let entity = find_jsonld_entity(&html, "PodcastEpisode").unwrap();
+
+        assert_eq!(
+            jsonld_str(&entity, "name").unwrap(),
+            "Episode 42: The Long Way Home"
+        );
+        assert_eq!(
+            jsonld_url(&entity["associatedMedia"]).unwrap(),
+            "https://traffic.example-cdn.com/field-recordings/episode-42.mp3"
+        );
+        assert!(find_jsonld_entity(&html, "Product").is_none());
+    }
+
+    #[test]
+    fn flattens_graph_entities() {
+        let html = html_from_bytes(JSONLD_GRAPH_HTML).unwrap();
+        let article = find_jsonld_entity(&html, "Article").unwrap();
+        let organization = find_jsonld_entity(&html, "Organization").unwrap();
+
+        assert_eq!(find_jsonld_entities(&html).len(), 4);
+        assert_eq!(
+            jsonld_str(&article, "headline").unwrap(),
+            "Repotting a monstera without the mess"
+        );
+        assert_eq!(jsonld_name(&article["author"]).unwrap(), "Priya Raman");
+        assert_eq!(jsonld_str(&organization, "name").unwrap(), "Leaf & Loam");
+        assert!(find_jsonld_entity(&html, "BreadcrumbList").is_some());
+    }
+
+    #[test]
+    fn finds_jsonld_tags() {
+        let html = html_from_bytes(JSONLD_ARTICLE_HTML).unwrap();
+
+        assert_eq!(
+            find_jsonld_tag(&html, JsonLdField::Title).unwrap(),
+            "Harbour ferry fleet to go electric by 2028"
+        );
+        assert_eq!(
+            find_jsonld_tag(&html, JsonLdField::Description).unwrap(),
+            "The city council approved a plan to replace all six diesel ferries with battery-electric vessels charged at the terminals."
+        );
+        assert_eq!(
+            find_jsonld_tag(&html, JsonLdField::Image).unwrap(),
+            "https://cdn.coastalgazette.example.com/images/ferry-terminal-16x9.jpg"
+        );
+        assert_eq!(
+            find_jsonld_tag(&html, JsonLdField::Url).unwrap(),
+            "https://coastalgazette.example.com/news/2024/05/harbour-ferries-electric"
+        );
+        assert_eq!(
+            find_jsonld_tag(&html, JsonLdField::Author).unwrap(),
+            "Maeve Donnelly"
+        );
+    }
+
+    #[test]
+    fn finds_jsonld_tags_of_page_entities_only() {
+        let html = html_from_bytes(JSONLD_GRAPH_HTML).unwrap();
+
+        assert_eq!(
+            find_jsonld_tag(&html, JsonLdField::Title).unwrap(),
+            "Repotting a monstera without the mess"
+        );
+        assert!(find_jsonld_tag(&html, JsonLdField::Url).is_none());
+    }
+
+    #[test]
+    fn prefers_article_types_over_other_entities() {
+        let html = html_from_bytes(JSONLD_COMPETING_TYPES_HTML).unwrap();
+
+        assert_eq!(
+            find_jsonld_tag(&html, JsonLdField::Title).unwrap(),
+            "Footbridge opens two months ahead of schedule"
+        );
+        assert_eq!(
+            find_jsonld_tag(&html, JsonLdField::Description).unwrap(),
+            "Cyclists and walkers can cross the river at Mill Lane from Friday."
+        );
+        assert_eq!(
+            find_jsonld_tag(&html, JsonLdField::Image).unwrap(),
+            "https://media.valleycourier.example.com/images/footbridge.jpg"
+        );
+    }
+}
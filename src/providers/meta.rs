@@ -0,0 +1,100 @@
+//! Uniform lookup across metadata providers
+//!
+//! Each provider (Open Graph, Twitter Cards, Schema.org, Dublin Core, ...)
+//! declares the same properties through its own tags. `MetaProvider` hides
+//! those differences behind a provider-neutral key, so fallback chains are
+//! expressed as a list of providers which can be extended or reordered.
+use scraper::Html;
+
+use crate::providers::og::OpenGraph;
+use crate::providers::schema::SchemaOrg;
+use crate::providers::twitter::TwitterCard;
+
+/// Providers used by `LinkPreview` for the title and description by default,
+/// in order of preference, as set by `ExtractionConfig::provider_order`
+pub const DEFAULT_PROVIDERS: [&dyn MetaProvider; 3] = [&OpenGraph, &TwitterCard, &SchemaOrg];
+
+/// A source of metadata declared through meta tags
+pub trait MetaProvider {
+    /// Retrieves the value the provider declares for `key` in the provided
+    /// `Html` instance.
+    ///
+    /// `key` is the name of the property without the provider's prefix, e.g.
+    /// `title` or `image:alt`. Providers naming a property differently map
+    /// the key to their own tag, for instance Schema.org reads `title` from
+    /// `itemprop="name"`.
+    fn lookup(&self, html: &Html, key: &str) -> Option<String>;
+}
+
+/// Retrieves the value declared for `key` by the first of the `providers`
+/// declaring it
+pub fn lookup_first(html: &Html, providers: &[&dyn MetaProvider], key: &str) -> Option<String> {
+    providers
+        .iter()
+        .find_map(|provider| provider.lookup(html, key))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html_from_bytes;
+    use crate::providers::dublincore::DublinCore;
+    use crate::providers::og::OpenGraph;
+    use crate::providers::schema::SchemaOrg;
+    use crate::providers::twitter::TwitterCard;
+    use crate::tests::{
+        DUBLIN_CORE_HTML, OG_COMPLIANT_HTML, SCHEMA_COMPLIANT_HTML, TWITTER_COMPLIANT_HTML,
+    };
+
+    use super::{lookup_first, MetaProvider, DEFAULT_PROVIDERS};
+
+    #[test]
+    fn looks_up_title_through_every_provider() {
+        let cases: [(&dyn MetaProvider, &[u8], &str); 4] = [
+            (
+                &OpenGraph,
+                OG_COMPLIANT_HTML,
+                "SEO Strategies for a better web",
+            ),
+            (
+                &TwitterCard,
+                TWITTER_COMPLIANT_HTML,
+                "SEO Strategies for a better web",
+            ),
+            (
+                &SchemaOrg,
+                SCHEMA_COMPLIANT_HTML,
+                "Schema.org tags are awesome",
+            ),
+            (
+                &DublinCore,
+                DUBLIN_CORE_HTML,
+                "Annual Report on Urban Beekeeping",
+            ),
+        ];
+
+        for (provider, bytes, title) in cases {
+            let html = html_from_bytes(bytes).unwrap();
+
+            assert_eq!(provider.lookup(&html, "title").unwrap(), title);
+        }
+    }
+
+    #[test]
+    fn looks_up_first_provider_declaring_key() {
+        let html = html_from_bytes(DUBLIN_CORE_HTML).unwrap();
+
+        assert_eq!(
+            lookup_first(&html, &DEFAULT_PROVIDERS, "title").unwrap(),
+            "Urban Beekeeping 2024"
+        );
+        assert_eq!(
+            lookup_first(&html, &[&DublinCore, &OpenGraph], "title").unwrap(),
+            "Annual Report on Urban Beekeeping"
+        );
+        assert_eq!(
+            lookup_first(&html, &[&OpenGraph, &DublinCore], "author").unwrap(),
+            "Greenfield Apiary Collective"
+        );
+        assert!(lookup_first(&html, &DEFAULT_PROVIDERS, "author").is_none());
+    }
+}
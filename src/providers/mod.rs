@@ -1,3 +1,10 @@
+pub mod applinks;
+pub mod dublincore;
+pub mod geo;
+pub mod jsonld;
+pub mod meta;
+pub mod oembed;
 pub mod og;
 pub mod schema;
+pub mod source;
 pub mod twitter;
@@ -0,0 +1,297 @@
+//! oEmbed bindings
+//!
+//! Sites supporting oEmbed advertise an endpoint describing the page, along
+//! with the HTML to embed it, through a
+//! `<link rel="alternate" type="application/json+oembed">` element.
+//!
+//! # References
+//! - [oEmbed](https://oembed.com)
+use std::sync::LazyLock;
+
+use scraper::{Html, Selector};
+use url::Url;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "json")]
+use serde_json::Value;
+
+use crate::html::document_base_url;
+use crate::providers::og::ImageMeta;
+use crate::urls::resolve_url;
+use crate::LinkPreview;
+
+#[cfg(feature = "fetch")]
+use crate::fetch::{fetch_bytes, Error as FetchError, FetchOptions};
+
+/// Media types of the JSON oEmbed endpoints, `text/json+oembed` being used
+/// by some sites in place of the registered one
+const OEMBED_JSON_TYPES: [&str; 2] = ["application/json+oembed", "text/json+oembed"];
+
+/// `Accept` header sent to oEmbed endpoints
+#[cfg(feature = "fetch")]
+const OEMBED_ACCEPT: &str = "application/json";
+
+/// An oEmbed response, describing a page and how to embed it
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OEmbed {
+    /// The resource type, one of `photo`, `video`, `link` or `rich`
+    #[cfg_attr(feature = "serde", serde(rename = "type"))]
+    pub kind: Option<String>,
+    pub title: Option<String>,
+    pub author_name: Option<String>,
+    pub author_url: Option<Url>,
+    pub provider_name: Option<String>,
+    pub provider_url: Option<Url>,
+    pub thumbnail_url: Option<Url>,
+    pub thumbnail_width: Option<u32>,
+    pub thumbnail_height: Option<u32>,
+    /// HTML to embed the resource, for `video` and `rich` types
+    pub html: Option<String>,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+}
+
+impl OEmbed {
+    /// Reads an `OEmbed` from a parsed oEmbed response, `None` when it's not
+    /// a JSON object. Invalid or empty fields are treated as missing.
+    #[cfg(feature = "json")]
+    pub fn from_value(value: &Value) -> Option<Self> {
+        let object = value.as_object()?;
+        let string = |key: &str| {
+            object
+                .get(key)
+                .and_then(Value::as_str)
+                .map(|value| value.trim())
+                .filter(|value| !value.is_empty())
+                .map(|value| value.to_string())
+        };
+        let url = |key: &str| string(key).and_then(|value| Url::parse(&value).ok());
+        let dimension = |key: &str| match object.get(key)? {
+            Value::Number(number) => number.as_u64().and_then(|value| value.try_into().ok()),
+            Value::String(value) => value.trim().parse().ok(),
+            _ => None,
+        };
+
+        Some(OEmbed {
+            kind: string("type"),
+            title: string("title"),
+            author_name: string("author_name"),
+            author_url: url("author_url"),
+            provider_name: string("provider_name"),
+            provider_url: url("provider_url"),
+            thumbnail_url: url("thumbnail_url"),
+            thumbnail_width: dimension("thumbnail_width"),
+            thumbnail_height: dimension("thumbnail_height"),
+            html: string("html"),
+            width: dimension("width"),
+            height: dimension("height"),
+        })
+    }
+}
+
+impl From<&OEmbed> for LinkPreview {
+    /// Maps the `title`, `author_name`, `provider_name` and thumbnail of the
+    /// oEmbed response onto `title`, `author`, `site_name`, `image_url` and
+    /// `image`. Use `LinkPreview::merge` to complete a preview with them.
+    fn from(oembed: &OEmbed) -> Self {
+        LinkPreview {
+            title: oembed.title.clone(),
+            author: oembed.author_name.clone(),
+            site_name: oembed.provider_name.clone(),
+            image_url: oembed.thumbnail_url.clone(),
+            image: oembed.thumbnail_url.clone().map(|url| ImageMeta {
+                url,
+                secure_url: None,
+                width: oembed.thumbnail_width,
+                height: oembed.thumbnail_height,
+                alt: None,
+                mime_type: None,
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+/// Finds the JSON oEmbed endpoint advertised by the provided `Html` instance
+/// through `<link rel="alternate" type="application/json+oembed">`, resolved
+/// against the document's `<base href>`
+pub fn find_oembed_endpoint(html: &Html) -> Option<Url> {
+    static SELECTOR: LazyLock<Selector> =
+        LazyLock::new(|| Selector::parse("link[rel~=\"alternate\"][type][href]").unwrap());
+    let base = document_base_url(html, None);
+
+    html.select(&SELECTOR)
+        .map(|element| element.value())
+        .filter(|element| {
+            element.attr("type").is_some_and(|kind| {
+                OEMBED_JSON_TYPES
+                    .iter()
+                    .any(|oembed_type| kind.trim().eq_ignore_ascii_case(oembed_type))
+            })
+        })
+        .find_map(|element| resolve_url(element.attr("href")?.trim(), base.as_ref()))
+}
+
+/// Fetches the provided oEmbed endpoint and retrieves its `OEmbed`
+#[cfg(feature = "fetch")]
+pub async fn fetch_oembed(endpoint: &Url) -> Result<OEmbed, FetchError> {
+    fetch_oembed_with_options(endpoint, &FetchOptions::default()).await
+}
+
+/// Fetches the provided oEmbed endpoint using the provided `FetchOptions` and
+/// retrieves its `OEmbed`
+#[cfg(feature = "fetch")]
+pub async fn fetch_oembed_with_options(
+    endpoint: &Url,
+    options: &FetchOptions,
+) -> Result<OEmbed, FetchError> {
+    let invalid = || FetchError::InvalidOEmbed(endpoint.to_string());
+    let options = FetchOptions {
+        accept: OEMBED_ACCEPT.to_string(),
+        ..options.clone()
+    };
+    let bytes = fetch_bytes(endpoint.as_str(), &options).await?;
+    let value = serde_json::from_slice::<Value>(&bytes).map_err(|_| invalid())?;
+
+    OEmbed::from_value(&value).ok_or_else(invalid)
+}
+
+/// Completes the provided `LinkPreview` with the `OEmbed` retrieved from
+/// `endpoint` using the provided `FetchOptions`, see `LinkPreview::merge`.
+/// The `LinkPreview` is kept as is when the endpoint can't be fetched.
+#[cfg(feature = "fetch")]
+pub(crate) async fn complete_with_oembed(
+    preview: LinkPreview,
+    endpoint: &Url,
+    options: &FetchOptions,
+) -> LinkPreview {
+    match fetch_oembed_with_options(endpoint, options).await {
+        Ok(oembed) => preview.merge(LinkPreview::from(&oembed)),
+        Err(_) => preview,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "json")]
+    use serde_json::json;
+    use url::Url;
+
+    use crate::html_from_bytes;
+    use crate::tests::{OG_COMPLIANT_HTML, YOUTUBE_VIDEO_HTML};
+    use crate::LinkPreview;
+
+    use super::{find_oembed_endpoint, OEmbed};
+
+    #[test]
+    fn finds_oembed_endpoint() {
+        let html = html_from_bytes(YOUTUBE_VIDEO_HTML).unwrap();
+
+        assert_eq!(
+            find_oembed_endpoint(&html).unwrap().as_str(),
+            "https://www.youtube.com/oembed?format=json&url=https%3A%2F%2Fwww.youtube.com%2Fwatch%3Fv%3D61JHONRXhjs"
+        );
+
+        let html = html_from_bytes(OG_COMPLIANT_HTML).unwrap();
+
+        assert!(find_oembed_endpoint(&html).is_none());
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn reads_oembed_response() {
+        let oembed = OEmbed::from_value(&json!({
+            "type": "video",
+            "version": "1.0",
+            "title": "Night trains across Europe",
+            "author_name": "Rail Journal",
+            "author_url": "https://www.youtube.com/@railjournal",
+            "provider_name": "YouTube",
+            "provider_url": "https://www.youtube.com/",
+            "thumbnail_url": "https://i.ytimg.com/vi/61JHONRXhjs/hqdefault.jpg",
+            "thumbnail_width": 480,
+            "thumbnail_height": "360",
+            "html": "<iframe src=\"https://www.youtube.com/embed/61JHONRXhjs\"></iframe>",
+            "width": 200,
+            "height": 113
+        }))
+        .unwrap();
+
+        assert_eq!(oembed.kind.as_deref(), Some("video"));
+        assert_eq!(oembed.thumbnail_height, Some(360));
+        assert_eq!(
+            oembed.author_url.unwrap().as_str(),
+            "https://www.youtube.com/@railjournal"
+        );
+        assert!(OEmbed::from_value(&json!(["video"])).is_none());
+    }
+
+    #[test]
+    fn maps_oembed_onto_link_preview() {
+        let oembed = OEmbed {
+            title: Some("Night trains across Europe".to_string()),
+            author_name: Some("Rail Journal".to_string()),
+            provider_name: Some("YouTube".to_string()),
+            thumbnail_url: Some(
+                Url::parse("https://i.ytimg.com/vi/61JHONRXhjs/hqdefault.jpg").unwrap(),
+            ),
+            thumbnail_width: Some(480),
+            thumbnail_height: Some(360),
+            ..Default::default()
+        };
+        let preview = LinkPreview {
+            title: Some("Night trains".to_string()),
+            ..Default::default()
+        }
+        .merge(LinkPreview::from(&oembed));
+
+        assert_eq!(preview.title.unwrap(), "Night trains");
+        assert_eq!(preview.author.unwrap(), "Rail Journal");
+        assert_eq!(preview.site_name.unwrap(), "YouTube");
+        assert_eq!(
+            preview.image_url.unwrap().as_str(),
+            "https://i.ytimg.com/vi/61JHONRXhjs/hqdefault.jpg"
+        );
+        assert_eq!(preview.image.unwrap().width, Some(480));
+    }
+
+    #[cfg(feature = "fetch")]
+    #[tokio::test]
+    async fn fetches_oembed() {
+        use crate::fetch::Error;
+        use crate::mock::{MockResponse, MockServer};
+
+        use super::fetch_oembed;
+
+        let server = MockServer::start(vec![
+            (
+                "/oembed",
+                MockResponse::new(200)
+                    .header("Content-Type", "application/json")
+                    .body(r#"{"type": "rich", "title": "Harbour ferries", "html": "<blockquote></blockquote>"}"#),
+            ),
+            (
+                "/broken",
+                MockResponse::new(200)
+                    .header("Content-Type", "application/json")
+                    .body("<html></html>"),
+            ),
+        ]);
+        let oembed = fetch_oembed(&Url::parse(&server.url("/oembed")).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(oembed.kind.as_deref(), Some("rich"));
+        assert_eq!(oembed.title.as_deref(), Some("Harbour ferries"));
+        assert_eq!(oembed.html.as_deref(), Some("<blockquote></blockquote>"));
+        assert!(server.requests()[0]
+            .to_lowercase()
+            .contains("accept: application/json\r\n"));
+        assert!(matches!(
+            fetch_oembed(&Url::parse(&server.url("/broken")).unwrap()).await,
+            Err(Error::InvalidOEmbed(_))
+        ));
+    }
+}
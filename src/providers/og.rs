@@ -8,8 +8,20 @@
 //!
 //! # References
 //! - [Official Documentation](https://ogp.me)
+use std::sync::LazyLock;
+
 use scraper::{Html, Selector};
+use std::collections::HashMap;
 use std::fmt;
+use url::Url;
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::html::{find_meta_element, meta_content};
+use crate::providers::meta::MetaProvider;
 
 /// OpenGraphTag meta tags collection
 pub enum OpenGraphTag {
@@ -35,8 +47,27 @@ pub enum OpenGraphTag {
     ImageHeight,
     /// Represents the "og:image:width" OpenGraph meta tag
     ImageWidth,
+    /// Represents the "og:image:alt" OpenGraph meta tag
+    ///
+    /// A description of what is in the image (not a caption).
+    ImageAlt,
     /// Represents the "og:site_name" OpenGraph meta tag
     SiteName,
+    /// Represents the "og:audio" OpenGraph meta tag
+    ///
+    /// A URL to an audio file to accompany this object.
+    Audio,
+    /// Represents the "og:video" OpenGraph meta tag
+    ///
+    /// A URL to a video file that complements this object.
+    Video,
+    /// Represents the "og:video:url" OpenGraph meta tag, equivalent to
+    /// "og:video"
+    VideoUrl,
+    /// Represents the "og:video:secure_url" OpenGraph meta tag
+    ///
+    /// An alternate URL to use if the webpage requires HTTPS.
+    VideoSecureUrl,
 }
 
 impl fmt::Debug for OpenGraphTag {
@@ -56,30 +87,261 @@ impl OpenGraphTag {
             OpenGraphTag::Locale => "locale",
             OpenGraphTag::ImageHeight => "image:height",
             OpenGraphTag::ImageWidth => "image:width",
+            OpenGraphTag::ImageAlt => "image:alt",
             OpenGraphTag::SiteName => "site_name",
+            OpenGraphTag::Audio => "audio",
+            OpenGraphTag::Video => "video",
+            OpenGraphTag::VideoUrl => "video:url",
+            OpenGraphTag::VideoSecureUrl => "video:secure_url",
         }
     }
 }
 
+/// Open Graph metadata provider, reading `<meta property="og:{key}">` tags
+pub struct OpenGraph;
+
+impl MetaProvider for OpenGraph {
+    fn lookup(&self, html: &Html, key: &str) -> Option<String> {
+        if let Some(element) = find_meta_element(html, "property", &format!("og:{key}")) {
+            if let Some(value) = meta_content(element.value()) {
+                return Some(value.trim().to_string());
+            }
+        }
+
+        None
+    }
+}
+
 /// Finds the OpenGraphTag tag specified in the provided `Html` instance
 pub fn find_og_tag(html: &Html, tag: OpenGraphTag) -> Option<String> {
-    let selector = Selector::parse(&format!("meta[property=\"og:{}\"]", tag.str())).unwrap();
+    OpenGraph.lookup(html, tag.str())
+}
+
+/// Counts the images declared through "og:image" OpenGraph meta tags in the
+/// provided `Html` instance. Structured properties such as "og:image:width"
+/// are not counted.
+pub fn count_og_images(html: &Html) -> usize {
+    static SELECTOR: LazyLock<Selector> =
+        LazyLock::new(|| Selector::parse("meta[property=\"og:image\"]").unwrap());
+
+    html.select(&SELECTOR).count()
+}
+
+/// Kinds of Open Graph media objects which support structured properties
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OgMediaKind {
+    /// Declared through the "og:image" OpenGraph meta tag
+    Image,
+    /// Declared through the "og:video" OpenGraph meta tag
+    Video,
+    /// Declared through the "og:audio" OpenGraph meta tag
+    Audio,
+}
+
+impl OgMediaKind {
+    fn str(&self) -> &str {
+        match self {
+            OgMediaKind::Image => "image",
+            OgMediaKind::Video => "video",
+            OgMediaKind::Audio => "audio",
+        }
+    }
+
+    fn from_base_tag(property: &str) -> Option<Self> {
+        match property {
+            "og:image" => Some(OgMediaKind::Image),
+            "og:video" => Some(OgMediaKind::Video),
+            "og:audio" => Some(OgMediaKind::Audio),
+            _ => None,
+        }
+    }
+}
+
+/// An Open Graph media object along with its structured properties
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OgMedia {
+    pub kind: OgMediaKind,
+    /// Content of the base tag (e.g. "og:image")
+    pub url: String,
+    /// Structured properties declared for this object, in document order and
+    /// without the base tag prefix. For instance `og:image:width` is
+    /// represented as `("width", "1200")`.
+    pub properties: Vec<(String, String)>,
+}
+
+impl OgMedia {
+    /// Retrieves the value of the first structured property with the
+    /// provided name
+    pub fn property(&self, name: &str) -> Option<&str> {
+        self.properties
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Parses the Open Graph media objects (`og:image`, `og:video` and
+/// `og:audio`) declared in the provided `Html` instance, grouping each one
+/// with its structured properties.
+///
+/// As per the Open Graph protocol, structured properties (e.g.
+/// `og:image:width`) belong to the latest base tag of the same kind declared
+/// before them, so grouping is sensitive to the order of the meta tags.
+/// Structured properties declared before any base tag of their kind are
+/// ignored, except for `:url` which starts a new object as it's equivalent
+/// to the base tag.
+pub fn parse_og_structured(html: &Html) -> Vec<OgMedia> {
+    static SELECTOR: LazyLock<Selector> =
+        LazyLock::new(|| Selector::parse("meta[property]").unwrap());
+    let mut media: Vec<OgMedia> = Vec::new();
 
-    if let Some(element) = html.select(&selector).next() {
-        if let Some(value) = element.value().attr("content") {
-            return Some(value.to_string());
+    for element in html.select(&SELECTOR) {
+        let element = element.value();
+        let (Some(property), Some(content)) = (element.attr("property"), meta_content(element))
+        else {
+            continue;
+        };
+
+        if let Some(kind) = OgMediaKind::from_base_tag(property) {
+            media.push(OgMedia {
+                kind,
+                url: content.to_string(),
+                properties: Vec::new(),
+            });
+            continue;
         }
+
+        for kind in [OgMediaKind::Image, OgMediaKind::Video, OgMediaKind::Audio] {
+            let Some(name) = property
+                .strip_prefix("og:")
+                .and_then(|property| property.strip_prefix(kind.str()))
+                .and_then(|property| property.strip_prefix(':'))
+            else {
+                continue;
+            };
+
+            let latest = media.iter_mut().rev().find(|object| object.kind == kind);
+
+            match latest {
+                Some(object) => object
+                    .properties
+                    .push((name.to_string(), content.to_string())),
+                None if name == "url" => media.push(OgMedia {
+                    kind,
+                    url: content.to_string(),
+                    properties: Vec::new(),
+                }),
+                None => {}
+            }
+        }
+    }
+
+    media
+}
+
+/// An Open Graph image along with its structured properties
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct ImageMeta {
+    /// Declared through either "og:image" or "og:image:url"
+    pub url: Url,
+    /// Declared through "og:image:secure_url"
+    pub secure_url: Option<Url>,
+    /// Declared through "og:image:width"
+    pub width: Option<u32>,
+    /// Declared through "og:image:height"
+    pub height: Option<u32>,
+    /// Declared through "og:image:alt"
+    pub alt: Option<String>,
+    /// MIME type declared through "og:image:type", e.g. "image/png"
+    pub mime_type: Option<String>,
+}
+
+impl ImageMeta {
+    /// Builds an `ImageMeta` from an image `OgMedia`, `None` if its URL is
+    /// invalid. Invalid structured properties are treated as missing.
+    fn from_og_media(media: &OgMedia) -> Option<Self> {
+        let dimension = |name: &str| {
+            media
+                .property(name)
+                .and_then(|value| value.trim().parse().ok())
+        };
+
+        Some(ImageMeta {
+            url: Url::parse(media.url.trim()).ok()?,
+            secure_url: media
+                .property("secure_url")
+                .and_then(|value| Url::parse(value.trim()).ok()),
+            width: dimension("width"),
+            height: dimension("height"),
+            alt: media.property("alt").map(|value| value.to_string()),
+            mime_type: media.property("type").map(|value| value.to_string()),
+        })
     }
+}
+
+/// Retrieves every image declared through "og:image" (or "og:image:url") in
+/// the provided `Html` instance along with its structured properties, in
+/// document order. Images with an invalid URL are skipped.
+///
+/// Properties are grouped as described in `parse_og_structured`.
+pub fn find_all_image_meta(html: &Html) -> Vec<ImageMeta> {
+    parse_og_structured(html)
+        .iter()
+        .filter(|media| media.kind == OgMediaKind::Image)
+        .filter_map(ImageMeta::from_og_media)
+        .collect()
+}
 
-    None
+/// Retrieves the first image declared through "og:image" (or
+/// "og:image:url") along with its structured properties, such as its
+/// dimensions. Non-numeric "og:image:width" and "og:image:height" values are
+/// treated as missing.
+pub fn find_og_image_meta(html: &Html) -> Option<ImageMeta> {
+    find_all_image_meta(html).into_iter().next()
+}
+
+/// Collects the trimmed `content` of every `og:*` and `article:*` meta tag in
+/// the provided `Html` instance, keyed by their `property` as declared, so
+/// tags not modeled by this crate (e.g. `og:price:amount` or
+/// `article:section`) can be read as well.
+///
+/// Repeated properties, such as multiple `og:image` tags, hold every value
+/// in document order.
+pub fn collect_og_tags(html: &Html) -> HashMap<String, Vec<String>> {
+    static SELECTOR: LazyLock<Selector> =
+        LazyLock::new(|| Selector::parse("meta[property]").unwrap());
+    let mut tags: HashMap<String, Vec<String>> = HashMap::new();
+
+    for element in html.select(&SELECTOR) {
+        let element = element.value();
+        let (Some(property), Some(content)) = (element.attr("property"), meta_content(element))
+        else {
+            continue;
+        };
+
+        if property.starts_with("og:") || property.starts_with("article:") {
+            tags.entry(property.to_string())
+                .or_default()
+                .push(content.trim().to_string());
+        }
+    }
+
+    tags
 }
 
 #[cfg(test)]
 mod tests {
+    use scraper::Html;
+
     use crate::html_from_bytes;
-    use crate::tests::OG_COMPLIANT_HTML;
+    use crate::tests::{OG_COMPLIANT_HTML, OG_IMAGES_HTML, OG_IMAGE_META_HTML, OG_STRUCTURED_HTML};
 
-    use super::{find_og_tag, OpenGraphTag};
+    use super::{
+        collect_og_tags, count_og_images, find_all_image_meta, find_og_image_meta, find_og_tag,
+        parse_og_structured, OgMediaKind, OpenGraphTag,
+    };
 
     #[test]
     fn retrieves_title_from_og_compliant_html() {
@@ -155,4 +417,132 @@ mod tests {
 
         assert_eq!(site_name, "TechPro");
     }
+
+    #[test]
+    fn groups_structured_properties_with_their_media() {
+        let html = html_from_bytes(OG_STRUCTURED_HTML).unwrap();
+        let media = parse_og_structured(&html);
+
+        assert_eq!(media.len(), 3);
+
+        assert_eq!(media[0].kind, OgMediaKind::Image);
+        assert_eq!(media[0].url, "https://example.com/photos/aurora-wide.jpg");
+        assert_eq!(media[0].property("width"), Some("1200"));
+        assert_eq!(media[0].property("height"), Some("630"));
+        assert_eq!(
+            media[0].property("secure_url"),
+            Some("https://secure.example.com/photos/aurora-wide.jpg")
+        );
+
+        assert_eq!(media[1].kind, OgMediaKind::Image);
+        assert_eq!(media[1].url, "https://example.com/photos/aurora-square.png");
+        assert_eq!(media[1].property("width"), Some("600"));
+        assert_eq!(media[1].property("height"), Some("600"));
+        assert_eq!(media[1].property("type"), Some("image/png"));
+
+        assert_eq!(media[2].kind, OgMediaKind::Video);
+        assert_eq!(media[2].property("width"), Some("1920"));
+        assert_eq!(media[2].property("height"), Some("1080"));
+    }
+
+    #[test]
+    fn counts_og_images() {
+        let html = html_from_bytes(OG_IMAGES_HTML).unwrap();
+
+        assert_eq!(count_og_images(&html), 3);
+    }
+
+    #[test]
+    fn finds_all_image_meta() {
+        let html = html_from_bytes(OG_IMAGE_META_HTML).unwrap();
+        let images = find_all_image_meta(&html);
+
+        assert_eq!(images.len(), 2);
+
+        assert_eq!(
+            images[0].url.as_str(),
+            "http://media.example.com/trips/dalmatia-hero.jpg"
+        );
+        assert_eq!(
+            images[0].secure_url.as_ref().unwrap().as_str(),
+            "https://media.example.com/trips/dalmatia-hero.jpg"
+        );
+        assert_eq!(images[0].width, Some(1200));
+        assert_eq!(images[0].height, Some(630));
+        assert_eq!(
+            images[0].alt.as_deref(),
+            Some("Two red kayaks beside a limestone cliff")
+        );
+        assert_eq!(images[0].mime_type.as_deref(), Some("image/jpeg"));
+
+        assert_eq!(
+            images[1].url.as_str(),
+            "http://media.example.com/trips/dalmatia-map.webp"
+        );
+        assert_eq!(
+            images[1].secure_url.as_ref().unwrap().as_str(),
+            "https://media.example.com/trips/dalmatia-map.webp"
+        );
+        assert_eq!(images[1].width, Some(800));
+        assert_eq!(images[1].height, Some(800));
+        assert_eq!(
+            images[1].alt.as_deref(),
+            Some("Route map from Split to Dubrovnik")
+        );
+        assert_eq!(images[1].mime_type.as_deref(), Some("image/webp"));
+    }
+
+    #[test]
+    fn finds_og_image_meta() {
+        let html = html_from_bytes(OG_COMPLIANT_HTML).unwrap();
+        let image = find_og_image_meta(&html).unwrap();
+
+        assert_eq!(
+            image.url.as_str(),
+            "https://www.apple.com/ac/structured-data/images/open_graph_logo.png?201809210816"
+        );
+        assert_eq!(image.width, Some(1200));
+        assert_eq!(image.height, Some(600));
+    }
+
+    #[test]
+    fn treats_non_numeric_dimensions_as_missing() {
+        let html = Html::parse_document(
+            r#"<html><head>
+                <meta property="og:image" content="https://example.com/banner.png" />
+                <meta property="og:image:width" content="auto" />
+                <meta property="og:image:height" content=" 480 " />
+            </head></html>"#,
+        );
+        let image = find_og_image_meta(&html).unwrap();
+
+        assert_eq!(image.width, None);
+        assert_eq!(image.height, Some(480));
+        assert!(find_og_image_meta(&Html::parse_document("<html></html>")).is_none());
+    }
+
+    #[test]
+    fn collects_every_og_and_article_tag() {
+        let html = html_from_bytes(OG_STRUCTURED_HTML).unwrap();
+        let tags = collect_og_tags(&html);
+
+        assert_eq!(
+            tags["og:image"],
+            [
+                "https://example.com/photos/aurora-wide.jpg",
+                "https://example.com/photos/aurora-square.png"
+            ]
+        );
+        assert_eq!(tags["og:image:width"], ["1200", "600"]);
+        assert_eq!(tags["og:type"], ["article"]);
+
+        let html = Html::parse_document(
+            r#"<meta property="article:section" content="Science"><meta property="og:price:amount" content="12.50"><meta property="fb:app_id" content="1234"><meta name="og:title" content="Named">"#,
+        );
+        let tags = collect_og_tags(&html);
+
+        assert_eq!(tags["article:section"], ["Science"]);
+        assert_eq!(tags["og:price:amount"], ["12.50"]);
+        assert_eq!(tags.len(), 2);
+    }
 }
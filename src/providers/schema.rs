@@ -1,4 +1,15 @@
-use scraper::{Html, Selector};
+use scraper::Html;
+use url::Url;
+
+#[cfg(feature = "json")]
+use serde_json::Value;
+
+use crate::html::find_meta_element;
+use crate::providers::meta::MetaProvider;
+use crate::providers::og::ImageMeta;
+
+#[cfg(feature = "json")]
+use crate::providers::jsonld::{find_jsonld_entities, jsonld_str, jsonld_url};
 
 /// Schema.org meta tags.
 pub enum SchemaMetaTag {
@@ -17,17 +28,131 @@ impl SchemaMetaTag {
     }
 }
 
+/// Schema.org metadata provider, reading `<meta itemprop="{key}">` tags.
+///
+/// The `title` key is read from the `name` property.
+pub struct SchemaOrg;
+
+impl MetaProvider for SchemaOrg {
+    fn lookup(&self, html: &Html, key: &str) -> Option<String> {
+        let property = match key {
+            "title" => "name",
+            key => key,
+        };
+        if let Some(element) = find_meta_element(html, "itemprop", property) {
+            if let Some(value) = element.value().attr("content") {
+                return Some(value.trim().to_string());
+            }
+        }
+
+        None
+    }
+}
+
 /// Finds the Schema.org tag specified in the provided `Html` instance
 pub fn find_schema_tag(html: &Html, tag: SchemaMetaTag) -> Option<String> {
-    let selector = Selector::parse(&format!("meta[itemprop=\"{}\"]", tag.str())).unwrap();
+    SchemaOrg.lookup(html, tag.str())
+}
 
-    if let Some(element) = html.select(&selector).next() {
-        if let Some(value) = element.value().attr("content") {
-            return Some(value.to_string());
+/// Retrieves the image of the page declared through Schema.org, along with
+/// its dimensions when available, in the following order:
+///
+/// - The `image` of the first JSON-LD entity declaring one, either an
+///   `ImageObject` (or a reference to one through its `@id`), a URL or a list
+///   of any of them, in which case the first valid one is used
+/// - Schema.org image meta tag (`image`), for which only the URL is known
+pub fn find_schema_image_meta(html: &Html) -> Option<ImageMeta> {
+    #[cfg(feature = "json")]
+    if let Some(image_meta) = find_jsonld_image_meta(html) {
+        return Some(image_meta);
+    }
+
+    find_schema_tag(html, SchemaMetaTag::Image)
+        .and_then(|image_url| image_meta_from_url(&image_url))
+}
+
+/// Retrieves the `image` of the first JSON-LD entity declaring a valid one
+#[cfg(feature = "json")]
+fn find_jsonld_image_meta(html: &Html) -> Option<ImageMeta> {
+    let entities = find_jsonld_entities(html);
+
+    entities
+        .iter()
+        .filter_map(|entity| entity.get("image"))
+        .find_map(|image| image_meta_from_jsonld(image, &entities))
+}
+
+/// Builds an `ImageMeta` from a JSON-LD image value, resolving `@id`
+/// references against the provided `entities`
+#[cfg(feature = "json")]
+fn image_meta_from_jsonld(value: &Value, entities: &[Value]) -> Option<ImageMeta> {
+    match value {
+        Value::String(image_url) => image_meta_from_url(image_url),
+        Value::Array(values) => values
+            .iter()
+            .find_map(|value| image_meta_from_jsonld(value, entities)),
+        Value::Object(_) if has_image_url(value) => image_meta_from_object(value),
+        Value::Object(_) => {
+            let id = jsonld_str(value, "@id")?;
+
+            entities
+                .iter()
+                .filter(|entity| jsonld_str(entity, "@id").as_deref() == Some(id.as_str()))
+                .find(|entity| has_image_url(entity))
+                .and_then(image_meta_from_object)
         }
+        _ => None,
     }
+}
+
+/// Checks whether the JSON-LD object declares the image URL itself rather
+/// than referencing an `ImageObject` through its `@id`
+#[cfg(feature = "json")]
+fn has_image_url(value: &Value) -> bool {
+    value
+        .get("url")
+        .or_else(|| value.get("contentUrl"))
+        .is_some()
+}
+
+/// Builds an `ImageMeta` from an `ImageObject`, along with its dimensions and
+/// its MIME type (`encodingFormat`)
+#[cfg(feature = "json")]
+fn image_meta_from_object(value: &Value) -> Option<ImageMeta> {
+    let mut image_meta = jsonld_url(value).and_then(|image_url| image_meta_from_url(&image_url))?;
+
+    image_meta.width = value.get("width").and_then(jsonld_dimension);
+    image_meta.height = value.get("height").and_then(jsonld_dimension);
+    image_meta.mime_type = jsonld_str(value, "encodingFormat");
+
+    Some(image_meta)
+}
 
-    None
+/// Builds an `ImageMeta` holding only the provided URL, `None` if invalid
+fn image_meta_from_url(image_url: &str) -> Option<ImageMeta> {
+    Some(ImageMeta {
+        url: Url::parse(image_url.trim()).ok()?,
+        secure_url: None,
+        width: None,
+        height: None,
+        alt: None,
+        mime_type: None,
+    })
+}
+
+/// Parses an image dimension declared either as a number, a numeric string
+/// or a `QuantitativeValue` holding one. Invalid values are treated as
+/// missing.
+#[cfg(feature = "json")]
+fn jsonld_dimension(value: &Value) -> Option<u32> {
+    match value {
+        Value::Number(number) => number
+            .as_u64()
+            .and_then(|number| u32::try_from(number).ok()),
+        Value::String(number) => number.trim().parse().ok(),
+        Value::Object(object) => object.get("value").and_then(jsonld_dimension),
+        _ => None,
+    }
 }
 
 #[cfg(test)]
@@ -35,7 +160,10 @@ mod tests {
     use crate::html_from_bytes;
     use crate::tests::SCHEMA_COMPLIANT_HTML;
 
-    use super::{find_schema_tag, SchemaMetaTag};
+    #[cfg(feature = "json")]
+    use crate::tests::JSONLD_IMAGE_OBJECT_HTML;
+
+    use super::{find_schema_image_meta, find_schema_tag, SchemaMetaTag};
 
     #[test]
     fn retrieves_schema_name() {
@@ -63,4 +191,29 @@ mod tests {
 
         assert_eq!(value, "https://www.example.com/image.jpg");
     }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn retrieves_schema_image_object_with_dimensions() {
+        let html = html_from_bytes(JSONLD_IMAGE_OBJECT_HTML).unwrap();
+        let image = find_schema_image_meta(&html).unwrap();
+
+        assert_eq!(
+            image.url.as_str(),
+            "https://trailnotes.example.com/media/west-highland-way.jpg"
+        );
+        assert_eq!(image.width, Some(1600));
+        assert_eq!(image.height, Some(900));
+        assert_eq!(image.mime_type.as_deref(), Some("image/jpeg"));
+    }
+
+    #[test]
+    fn falls_back_to_schema_image_string() {
+        let html = html_from_bytes(SCHEMA_COMPLIANT_HTML).unwrap();
+        let image = find_schema_image_meta(&html).unwrap();
+
+        assert_eq!(image.url.as_str(), "https://www.example.com/image.jpg");
+        assert!(image.width.is_none());
+        assert!(image.height.is_none());
+    }
 }
@@ -0,0 +1,97 @@
+//! Sources metadata can be extracted from
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::providers::dublincore::DublinCore;
+use crate::providers::meta::MetaProvider;
+use crate::providers::og::OpenGraph;
+use crate::providers::schema::SchemaOrg;
+use crate::providers::twitter::TwitterCard;
+
+/// Source a piece of metadata was, or should be, extracted from
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ExtractionSource {
+    /// OpenGraph meta tags (`og:*`)
+    OpenGraph,
+    /// Twitter Card meta tags (`twitter:*`)
+    Twitter,
+    /// Schema.org meta tags (`<meta itemprop>`)
+    Schema,
+    /// The document's own elements, such as `<title>` or the first paragraph
+    Html,
+    /// JSON-LD script blocks (`<script type="application/ld+json">`)
+    JsonLd,
+    /// Microdata items (`itemscope` and `itemprop` attributes)
+    Microdata,
+    /// Dublin Core meta tags (`DC.*`)
+    DublinCore,
+}
+
+impl ExtractionSource {
+    /// Retrieves the `MetaProvider` reading this source's meta tags, `None`
+    /// for sources which aren't declared through meta tags such as `Html` or
+    /// `JsonLd`
+    pub fn meta_provider(&self) -> Option<&'static dyn MetaProvider> {
+        match self {
+            ExtractionSource::OpenGraph => Some(&OpenGraph),
+            ExtractionSource::Twitter => Some(&TwitterCard),
+            ExtractionSource::Schema => Some(&SchemaOrg),
+            ExtractionSource::DublinCore => Some(&DublinCore),
+            ExtractionSource::Html | ExtractionSource::JsonLd | ExtractionSource::Microdata => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::html_from_bytes;
+    use crate::tests::DUBLIN_CORE_HTML;
+
+    use super::ExtractionSource;
+
+    #[test]
+    fn sources_can_be_used_as_map_keys() {
+        let sources = [
+            ExtractionSource::OpenGraph,
+            ExtractionSource::Twitter,
+            ExtractionSource::Schema,
+            ExtractionSource::Html,
+            ExtractionSource::JsonLd,
+            ExtractionSource::Microdata,
+            ExtractionSource::DublinCore,
+        ];
+        let priorities: HashMap<ExtractionSource, usize> = sources
+            .iter()
+            .enumerate()
+            .map(|(priority, source)| (*source, priority))
+            .collect();
+
+        assert_eq!(priorities.len(), sources.len());
+        assert_eq!(priorities[&ExtractionSource::OpenGraph], 0);
+        assert_eq!(priorities[&ExtractionSource::DublinCore], 6);
+    }
+
+    #[test]
+    fn maps_meta_tag_sources_to_providers() {
+        let html = html_from_bytes(DUBLIN_CORE_HTML).unwrap();
+        let title = |source: ExtractionSource| {
+            source
+                .meta_provider()
+                .and_then(|provider| provider.lookup(&html, "title"))
+        };
+
+        assert_eq!(
+            title(ExtractionSource::DublinCore).unwrap(),
+            "Annual Report on Urban Beekeeping"
+        );
+        assert_eq!(
+            title(ExtractionSource::OpenGraph).unwrap(),
+            "Urban Beekeeping 2024"
+        );
+        assert!(ExtractionSource::Html.meta_provider().is_none());
+        assert!(ExtractionSource::JsonLd.meta_provider().is_none());
+    }
+}
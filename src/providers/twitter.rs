@@ -1,4 +1,7 @@
-use scraper::{Html, Selector};
+use scraper::Html;
+
+use crate::html::{find_meta_element, meta_content};
+use crate::providers::meta::MetaProvider;
 
 /// Twittet meta tags.
 pub enum TwitterMetaTag {
@@ -15,8 +18,13 @@ pub enum TwitterMetaTag {
     Creator,
     /// Twitter Card Image
     Image,
+    /// A text description of the image conveying its essential nature to
+    /// visually impaired users.
+    ImageAlt,
     /// Card description
     Description,
+    /// Canonical URL of the page the card describes
+    Url,
 }
 
 impl TwitterMetaTag {
@@ -27,30 +35,61 @@ impl TwitterMetaTag {
             TwitterMetaTag::Site => "site",
             TwitterMetaTag::Creator => "creator",
             TwitterMetaTag::Image => "image",
+            TwitterMetaTag::ImageAlt => "image:alt",
             TwitterMetaTag::Description => "description",
+            TwitterMetaTag::Url => "url",
         }
     }
 }
 
+/// Twitter Cards metadata provider, reading `<meta name="twitter:{key}">`
+/// tags
+pub struct TwitterCard;
+
+impl MetaProvider for TwitterCard {
+    fn lookup(&self, html: &Html, key: &str) -> Option<String> {
+        find_twitter_name(html, key)
+    }
+}
+
 /// Finds the Twitter tag specified in the provided `Html` instance
 pub fn find_twitter_tag(html: &Html, tag: TwitterMetaTag) -> Option<String> {
-    let selector = Selector::parse(&format!("meta[name=\"twitter:{}\"]", tag.str())).unwrap();
+    find_twitter_name(html, tag.str())
+}
 
-    if let Some(element) = html.select(&selector).next() {
-        if let Some(value) = element.value().attr("content") {
-            return Some(value.to_string());
+/// Retrieves the labeled data pairs (e.g. `("Reading time", "5 min")`) from
+/// the `twitter:label{N}` and `twitter:data{N}` meta tags, in order of `N`
+/// starting at 1. Labels without data and data without label are skipped.
+pub fn find_twitter_labels(html: &Html) -> Vec<(String, String)> {
+    let mut labels = Vec::new();
+
+    for index in 1.. {
+        let label = find_twitter_name(html, &format!("label{index}"));
+        let data = find_twitter_name(html, &format!("data{index}"));
+
+        match (label, data) {
+            (Some(label), Some(data)) => labels.push((label, data)),
+            (None, None) => break,
+            _ => continue,
         }
     }
 
-    None
+    labels
+}
+
+/// Retrieves the `content` of the `twitter:{name}` meta tag
+fn find_twitter_name(html: &Html, name: &str) -> Option<String> {
+    find_meta_element(html, "name", &format!("twitter:{name}"))
+        .and_then(|element| meta_content(element.value()))
+        .map(|value| value.to_string())
 }
 
 #[cfg(test)]
 mod tests {
     use crate::html_from_bytes;
-    use crate::tests::TWITTER_COMPLIANT_HTML;
+    use crate::tests::{META_VALUE_ATTRIBUTE_HTML, TWITTER_COMPLIANT_HTML, TWITTER_LABELS_HTML};
 
-    use super::{find_twitter_tag, TwitterMetaTag};
+    use super::{find_twitter_labels, find_twitter_tag, TwitterMetaTag};
 
     #[test]
     fn retrieves_card() {
@@ -60,6 +99,14 @@ mod tests {
         assert_eq!(value, "summary_large_image");
     }
 
+    #[test]
+    fn retrieves_card_from_value_attribute() {
+        let html = html_from_bytes(META_VALUE_ATTRIBUTE_HTML).unwrap();
+        let value = find_twitter_tag(&html, TwitterMetaTag::Card).unwrap();
+
+        assert_eq!(value, "summary_large_image");
+    }
+
     #[test]
     fn retrieves_title() {
         let html = html_from_bytes(TWITTER_COMPLIANT_HTML).unwrap();
@@ -99,4 +146,25 @@ mod tests {
 
         assert_eq!(value, "@EstebanBorai");
     }
+
+    #[test]
+    fn retrieves_labels_in_order() {
+        let html = html_from_bytes(TWITTER_LABELS_HTML).unwrap();
+        let labels = find_twitter_labels(&html);
+
+        assert_eq!(
+            labels,
+            vec![
+                ("Reading time".to_string(), "5 min".to_string()),
+                ("Filed under".to_string(), "Kitchen".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn retrieves_no_labels() {
+        let html = html_from_bytes(TWITTER_COMPLIANT_HTML).unwrap();
+
+        assert!(find_twitter_labels(&html).is_empty());
+    }
 }
@@ -0,0 +1,263 @@
+use std::net::{IpAddr, Ipv4Addr};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer};
+use url::{Host, Url};
+
+/// Query parameters used to track visitors or their sessions rather than to
+/// identify the resource
+const TRACKING_PARAMS: [&str; 14] = [
+    "fbclid",
+    "gclid",
+    "dclid",
+    "msclkid",
+    "yclid",
+    "igshid",
+    "mc_cid",
+    "mc_eid",
+    "_ga",
+    "ref_src",
+    "sessionid",
+    "session_id",
+    "phpsessid",
+    "jsessionid",
+];
+
+/// Prefixes of query parameters used to track visitors, e.g. `utm_source`
+const TRACKING_PARAM_PREFIXES: [&str; 1] = ["utm_"];
+
+/// Parses the provided URL, resolving it against `base` when one is
+/// provided. Absolute URLs are parsed as is regardless of `base`.
+pub fn resolve_url(value: &str, base: Option<&Url>) -> Option<Url> {
+    match base {
+        Some(base) => base.join(value).ok(),
+        None => Url::parse(value).ok(),
+    }
+}
+
+/// Removes tracking and session query parameters (e.g. `utm_source`,
+/// `fbclid` or `sessionid`) from the provided URL, keeping any other
+/// parameter in its original order. Parameter names are compared
+/// case-insensitively and the query is dropped when no parameter is left.
+pub fn strip_tracking_params(url: &mut Url) {
+    if url.query().is_none() {
+        return;
+    }
+
+    let kept: Vec<(String, String)> = url
+        .query_pairs()
+        .filter(|(name, _)| {
+            let name = name.to_lowercase();
+
+            !TRACKING_PARAMS.contains(&name.as_str())
+                && !TRACKING_PARAM_PREFIXES
+                    .iter()
+                    .any(|prefix| name.starts_with(prefix))
+        })
+        .map(|(name, value)| (name.into_owned(), value.into_owned()))
+        .collect();
+
+    if kept.is_empty() {
+        url.set_query(None);
+    } else {
+        url.query_pairs_mut().clear().extend_pairs(kept);
+    }
+}
+
+/// Domain suffixes reserved for hosts on the local network or the machine
+/// itself
+const INTERNAL_DOMAIN_SUFFIXES: [&str; 2] = [".local", ".localhost"];
+
+/// Checks whether the provided host, either a domain or an IP address (IPv6
+/// addresses may be enclosed in brackets), is not reachable from the public
+/// internet: `localhost`, domains under `.local` or `.localhost`, and IP
+/// addresses classified as internal by `is_internal_ip`
+pub fn is_internal_host(host: &str) -> bool {
+    match Host::parse(host.trim()) {
+        Ok(Host::Ipv4(ip)) => is_internal_ip(IpAddr::V4(ip)),
+        Ok(Host::Ipv6(ip)) => is_internal_ip(IpAddr::V6(ip)),
+        Ok(Host::Domain(domain)) => {
+            let domain = domain.trim_end_matches('.');
+
+            domain == "localhost"
+                || INTERNAL_DOMAIN_SUFFIXES
+                    .iter()
+                    .any(|suffix| domain.ends_with(suffix))
+        }
+        Err(_) => false,
+    }
+}
+
+/// Checks whether the provided IP address belongs to the host itself or to a
+/// private network rather than to the public internet: loopback, private
+/// (including IPv6 unique local addresses), link-local, shared address space
+/// (carrier-grade NAT), "this network" (`0.0.0.0/8`), unspecified and
+/// broadcast addresses.
+///
+/// IPv6 addresses embedding an IPv4 address are checked as the embedded
+/// address: IPv4-mapped (`::ffff:a.b.c.d`), IPv4-compatible (`::a.b.c.d`),
+/// NAT64 (`64:ff9b::/96`) and 6to4 (`2002::/16`) addresses.
+pub fn is_internal_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [first, second, ..] = ip.octets();
+
+            ip.is_loopback()
+                || ip.is_private()
+                || ip.is_link_local()
+                || ip.is_broadcast()
+                || first == 0
+                || (first == 100 && (second & 0xc0) == 64)
+        }
+        IpAddr::V6(ip) => {
+            if let Some(ip) = ip.to_ipv4_mapped() {
+                return is_internal_ip(IpAddr::V4(ip));
+            }
+
+            let segments = ip.segments();
+            let embedded = match segments {
+                [0, 0, 0, 0, 0, 0, high, low] | [0x64, 0xff9b, 0, 0, 0, 0, high, low] => {
+                    Some((high, low))
+                }
+                [0x2002, high, low, ..] => Some((high, low)),
+                _ => None,
+            };
+
+            // Covers `::` and `::1` as well, embedding `0.0.0.0` and `0.0.0.1`
+            if let Some((high, low)) = embedded {
+                let ip = Ipv4Addr::from((u32::from(high) << 16) | u32::from(low));
+
+                return is_internal_ip(IpAddr::V4(ip));
+            }
+
+            let first = segments[0];
+
+            (first & 0xfe00) == 0xfc00 || (first & 0xffc0) == 0xfe80
+        }
+    }
+}
+
+/// Deserializes an optional URL from a string, treating a string which is not
+/// a valid absolute URL as missing instead of failing, for use with
+/// `#[serde(deserialize_with = "...")]`
+#[cfg(feature = "serde")]
+pub fn deserialize_lenient_url<'de, D>(deserializer: D) -> Result<Option<Url>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = Option::<String>::deserialize(deserializer)?;
+
+    Ok(value.and_then(|value| Url::parse(value.trim()).ok()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::IpAddr;
+
+    use url::Url;
+
+    use super::{is_internal_host, is_internal_ip, resolve_url, strip_tracking_params};
+
+    #[test]
+    fn resolves_relative_urls_against_base() {
+        let base = Url::parse("https://example.com/blog/post").unwrap();
+
+        assert_eq!(
+            resolve_url("/images/a.png", Some(&base)).unwrap().as_str(),
+            "https://example.com/images/a.png"
+        );
+        assert_eq!(
+            resolve_url("https://cdn.example.org/b.png", Some(&base))
+                .unwrap()
+                .as_str(),
+            "https://cdn.example.org/b.png"
+        );
+        assert!(resolve_url("/images/a.png", None).is_none());
+    }
+
+    #[test]
+    fn strips_tracking_params() {
+        let mut url = Url::parse(
+            "https://example.com/post?id=7&utm_source=x&UTM_Medium=y&fbclid=abc&PHPSESSID=1",
+        )
+        .unwrap();
+
+        strip_tracking_params(&mut url);
+        assert_eq!(url.as_str(), "https://example.com/post?id=7");
+
+        let mut url = Url::parse("https://example.com/post?utm_campaign=launch#intro").unwrap();
+
+        strip_tracking_params(&mut url);
+        assert_eq!(url.as_str(), "https://example.com/post#intro");
+    }
+
+    #[test]
+    fn classifies_internal_ips() {
+        let internal = [
+            "127.0.0.1",
+            "10.1.2.3",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "100.64.0.1",
+            "0.0.0.0",
+            "0.1.2.3",
+            "::",
+            "::1",
+            "fd00::1",
+            "fe80::1",
+            "::ffff:127.0.0.1",
+            "::127.0.0.1",
+            "::10.0.0.1",
+            "64:ff9b::a9fe:a9fe",
+            "64:ff9b::127.0.0.1",
+            "2002:7f00:1::",
+            "2002:c0a8:101::1",
+        ];
+        let public = [
+            "93.184.216.34",
+            "8.8.8.8",
+            "2606:4700:4700::1111",
+            "::8.8.8.8",
+            "64:ff9b::808:808",
+            "2002:808:808::1",
+        ];
+
+        for ip in internal {
+            assert!(is_internal_ip(ip.parse::<IpAddr>().unwrap()), "{ip}");
+        }
+
+        for ip in public {
+            assert!(!is_internal_ip(ip.parse::<IpAddr>().unwrap()), "{ip}");
+        }
+    }
+
+    #[test]
+    fn classifies_internal_hosts() {
+        for host in [
+            "localhost",
+            "LOCALHOST.",
+            "api.localhost",
+            "printer.local",
+            "127.0.0.1",
+            "127.1",
+            "10.0.12.7",
+            "192.168.1.20",
+            "[::1]",
+            "[fd12:3456::1]",
+        ] {
+            assert!(is_internal_host(host), "{host}");
+        }
+
+        for host in [
+            "example.com",
+            "localhost.example.com",
+            "local.example.com",
+            "8.8.8.8",
+            "[2606:4700:4700::1111]",
+            "",
+        ] {
+            assert!(!is_internal_host(host), "{host}");
+        }
+    }
+}